@@ -0,0 +1,72 @@
+//! Benchmarks for parsing colour names and hex codes
+//!
+//! `legacy` is the earlier implementation of `Purple::parse`, which lowercased
+//! the input into a new `String` and matched it against every name and hex
+//! code, kept here as a baseline for the table driven lookup.
+//!
+
+use std::hint::black_box;
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use named_colour::ext::Purple;
+
+const INPUTS: [&str; 6] = [
+    "indigo",
+    "LightPink",
+    "#FF69B4",
+    "c71585",
+    "MediumVioletRed",
+    "not a colour",
+];
+
+fn legacy(name: &str) -> Option<Purple> {
+    match name.to_lowercase().as_str() {
+        "#4b0082" | "4b0082" | "indigo" => Some(Purple::Indigo),
+        "#800080" | "800080" | "purple" => Some(Purple::Purple),
+        "#8b008b" | "8b008b" | "darkmagenta" => Some(Purple::DarkMagenta),
+        "#9400d3" | "9400d3" | "darkviolet" => Some(Purple::DarkViolet),
+        "#483d8b" | "483d8b" | "darkslateblue" => Some(Purple::DarkSlateBlue),
+        "#8a2be2" | "8a2be2" | "blueviolet" => Some(Purple::BlueViolet),
+        "#9932cc" | "9932cc" | "darkorchid" => Some(Purple::DarkOrchid),
+        "fuchsia" => Some(Purple::Fuchsia),
+        "#ff00ff" | "ff00ff" | "magenta" => Some(Purple::Magenta),
+        "#6a5acd" | "6a5acd" | "slateblue" => Some(Purple::SlateBlue),
+        "#7b68ee" | "7b68ee" | "mediumslateblue" => Some(Purple::MediumSlateBlue),
+        "#ba55d3" | "ba55d3" | "mediumorchid" => Some(Purple::MediumOrchid),
+        "#9370db" | "9370db" | "mediumpurple" => Some(Purple::MediumPurple),
+        "#da70d6" | "da70d6" | "orchid" => Some(Purple::Orchid),
+        "#ee82ee" | "ee82ee" | "violet" => Some(Purple::Violet),
+        "#dda0dd" | "dda0dd" | "plum" => Some(Purple::Plum),
+        "#d8bfd8" | "d8bfd8" | "thistle" => Some(Purple::Thistle),
+        "#e6e6fa" | "e6e6fa" | "lavender" => Some(Purple::Lavender),
+        "#ffc0cb" | "ffc0cb" | "pink" => Some(Purple::Pink),
+        "#c71585" | "c71585" | "mediumvioletred" => Some(Purple::MediumVioletRed),
+        "#db7093" | "db7093" | "palevioletred" => Some(Purple::PaleVioletRed),
+        "#ff1493" | "ff1493" | "deeppink" => Some(Purple::DeepPink),
+        "#ff69b4" | "ff69b4" | "hotpink" => Some(Purple::HotPink),
+        "#ffb6c1" | "ffb6c1" | "lightpink" => Some(Purple::LightPink),
+        _ => None,
+    }
+}
+
+fn parse(c: &mut Criterion) {
+    let mut group = c.benchmark_group("purple");
+    group.bench_function("legacy", |b| {
+        b.iter(|| {
+            for input in INPUTS {
+                black_box(legacy(black_box(input)));
+            }
+        })
+    });
+    group.bench_function("table", |b| {
+        b.iter(|| {
+            for input in INPUTS {
+                black_box(Purple::parse(black_box(input)));
+            }
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, parse);
+criterion_main!(benches);
@@ -0,0 +1,218 @@
+//! Photoshop colour swatch (`.aco`) files
+//!
+//! An ACO file holds a version 1 section with unnamed colours, optionally
+//! followed by a version 2 section repeating the colours with their names.
+//! Colours are written in both sections as RGB. RGB and greyscale colours can
+//! be read; other colour spaces are reported as errors.
+//!
+
+use rgb::Rgb;
+
+use super::{encode_utf16, fit, Reader};
+use crate::{palette::Palette, ToHex};
+
+const FORMAT: &str = "ACO";
+
+const RGB_SPACE: u16 = 0;
+const GREYSCALE_SPACE: u16 = 8;
+
+/// Write a palette as an ACO file with version 1 and version 2 sections
+///
+/// ## Errors
+///
+/// Returns an error if the palette holds more than 65535 colours.
+///
+/// ## Example
+///
+/// ```
+/// # use named_colour::adobe::aco;
+/// # use named_colour::ext::Brown;
+/// # use named_colour::palette::Palette;
+///     let bytes = aco::to_aco(&Palette::from_family::<Brown>()).unwrap();
+///     let imported = aco::from_aco(&bytes).unwrap();
+///
+///     assert_eq!(Some(Brown::Peru.to_rgb()), imported.get("Peru"));
+/// ```
+pub fn to_aco(palette: &Palette) -> Result<Vec<u8>, String> {
+    let count: u16 = fit(FORMAT, "colours", palette.len())?;
+    let mut bytes = Vec::new();
+
+    bytes.extend_from_slice(&1_u16.to_be_bytes());
+    bytes.extend_from_slice(&count.to_be_bytes());
+    for entry in palette.iter() {
+        push_colour(&mut bytes, entry.colour());
+    }
+
+    bytes.extend_from_slice(&2_u16.to_be_bytes());
+    bytes.extend_from_slice(&count.to_be_bytes());
+    for entry in palette.iter() {
+        push_colour(&mut bytes, entry.colour());
+        let (length, name) = encode_utf16(entry.name());
+        bytes.extend_from_slice(&fit::<u32>(FORMAT, "characters", length)?.to_be_bytes());
+        bytes.extend_from_slice(&name);
+    }
+
+    Ok(bytes)
+}
+
+fn push_colour(bytes: &mut Vec<u8>, colour: Rgb<u8>) {
+    bytes.extend_from_slice(&RGB_SPACE.to_be_bytes());
+    for value in [colour.r, colour.g, colour.b, 0] {
+        bytes.extend_from_slice(&(value as u16 * 257).to_be_bytes());
+    }
+}
+
+/// Read an ACO file into a palette
+///
+/// Names are taken from the version 2 section. A file with only a version 1
+/// section has no names, so its colours are named by their hex code, e.g.
+/// `#4682B4`. Every colour becomes an entry, even where a name is repeated.
+pub fn from_aco(bytes: &[u8]) -> Result<Palette, String> {
+    let mut reader = Reader::new(FORMAT, bytes);
+
+    let mut version = reader.u16()?;
+    if version == 1 {
+        let unnamed = read_section(&mut reader, false)?;
+        if reader.is_empty() {
+            return Ok(unnamed);
+        }
+        version = reader.u16()?;
+    }
+    if version != 2 {
+        return Err(reader.error("unsupported version"));
+    }
+
+    read_section(&mut reader, true)
+}
+
+fn read_section(reader: &mut Reader, named: bool) -> Result<Palette, String> {
+    let count = reader.u16()?;
+    let mut palette = Palette::new();
+
+    for _ in 0..count {
+        let space = reader.u16()?;
+        let values = [reader.u16()?, reader.u16()?, reader.u16()?, reader.u16()?];
+        let colour = match space {
+            RGB_SPACE => Rgb::new(to_u8(values[0]), to_u8(values[1]), to_u8(values[2])),
+            GREYSCALE_SPACE if values[0] <= 10000 => {
+                let grey = ((values[0] as u32 * 255 + 5000) / 10000) as u8;
+                Rgb::new(grey, grey, grey)
+            }
+            _ => return Err(reader.error("unsupported colour space")),
+        };
+
+        let name = match named {
+            true => {
+                let length = reader.u32()? as usize;
+                reader.utf16(length)?
+            }
+            false => String::new(),
+        };
+        match name.as_str() {
+            "" => palette.push(&colour.as_hex(), colour),
+            name => palette.push(name, colour),
+        }
+    }
+
+    Ok(palette)
+}
+
+fn to_u8(value: u16) -> u8 {
+    ((value as u32 * 255 + 32767) / 65535) as u8
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ext::Cyan;
+    use rstest::rstest;
+
+    /// "Primary" rgb(18, 52, 86) and "Paper" rgb(255, 255, 255) in version 1
+    /// and version 2 sections
+    #[rustfmt::skip]
+    const FIXTURE: &[u8] = &[
+        0x00, 0x01, 0x00, 0x02,
+        0x00, 0x00, 0x12, 0x12, 0x34, 0x34, 0x56, 0x56, 0x00, 0x00,
+        0x00, 0x00, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x00, 0x00,
+        0x00, 0x02, 0x00, 0x02,
+        0x00, 0x00, 0x12, 0x12, 0x34, 0x34, 0x56, 0x56, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x08,
+        0x00, b'P', 0x00, b'r', 0x00, b'i', 0x00, b'm', 0x00, b'a', 0x00, b'r', 0x00, b'y',
+        0x00, 0x00,
+        0x00, 0x00, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x06,
+        0x00, b'P', 0x00, b'a', 0x00, b'p', 0x00, b'e', 0x00, b'r', 0x00, 0x00,
+    ];
+
+    /// A version 1 file with a greyscale colour at 50%
+    #[rustfmt::skip]
+    const VERSION_1_FIXTURE: &[u8] = &[
+        0x00, 0x01, 0x00, 0x01,
+        0x00, 0x08, 0x13, 0x88, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ];
+
+    #[test]
+    fn test_from_fixture() {
+        let palette = from_aco(FIXTURE).unwrap();
+
+        assert_eq!(2, palette.len());
+        assert_eq!(Some(Rgb::new(18, 52, 86)), palette.get("Primary"));
+        assert_eq!(Some(Rgb::new(255, 255, 255)), palette.get("Paper"));
+    }
+
+    #[test]
+    fn test_round_trip_fixture() {
+        let palette = from_aco(FIXTURE).unwrap();
+
+        assert_eq!(FIXTURE, to_aco(&palette).unwrap().as_slice());
+    }
+
+    #[test]
+    fn test_from_version_1_fixture() {
+        let palette = from_aco(VERSION_1_FIXTURE).unwrap();
+
+        assert_eq!(Some(Rgb::new(128, 128, 128)), palette.get("#808080"));
+    }
+
+    #[test]
+    fn test_from_version_2_section_only() {
+        let palette = from_aco(&FIXTURE[24..]).unwrap();
+
+        assert_eq!(Some(Rgb::new(18, 52, 86)), palette.get("Primary"));
+    }
+
+    #[test]
+    fn test_round_trip_family() {
+        let cyans = Palette::from_family::<Cyan>();
+
+        assert_eq!(cyans, from_aco(&to_aco(&cyans).unwrap()).unwrap());
+    }
+
+    #[test]
+    fn test_from_aco_keeps_repeated_names() {
+        let mut palette = Palette::new();
+        palette.push("Untitled", Rgb::new(18, 52, 86));
+        palette.push("Untitled", Rgb::new(255, 255, 255));
+
+        assert_eq!(palette, from_aco(&to_aco(&palette).unwrap()).unwrap());
+    }
+
+    #[test]
+    fn test_to_aco_too_many_colours() {
+        let mut palette = Palette::new();
+        for _ in 0..=u16::MAX as usize {
+            palette.push("Black", Rgb::new(0, 0, 0));
+        }
+
+        assert!(to_aco(&palette).is_err());
+    }
+
+    #[rstest]
+    #[case(&[])]
+    #[case(&[0x00, 0x03, 0x00, 0x00])]
+    #[case(&FIXTURE[..FIXTURE.len() - 2])]
+    #[case(&[0x00, 0x01, 0x00, 0x01, 0x00, 0x02, 0, 0, 0, 0, 0, 0, 0, 0])]
+    fn test_invalid(#[case] bytes: &[u8]) {
+        assert!(from_aco(bytes).is_err());
+    }
+}
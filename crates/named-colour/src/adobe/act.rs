@@ -0,0 +1,142 @@
+//! Adobe colour table (`.act`) files
+//!
+//! An ACT file is a table of 256 RGB colours, optionally followed by the
+//! number of colours in use and the index of the transparent colour. The
+//! format has no colour names.
+//!
+
+use rgb::Rgb;
+
+use crate::{palette::Palette, ToHex};
+
+const TABLE_LENGTH: usize = 256;
+const NO_TRANSPARENCY: u16 = 0xFFFF;
+
+/// Write a palette as an ACT file
+///
+/// Unused table entries are filled with black and the number of colours in
+/// use is recorded after the table.
+///
+/// ## Errors
+///
+/// Returns an error if the palette holds more than 256 colours.
+pub fn to_act(palette: &Palette) -> Result<Vec<u8>, String> {
+    if palette.len() > TABLE_LENGTH {
+        return Err(format!(
+            "An ACT colour table holds at most {TABLE_LENGTH} colours, not {}",
+            palette.len()
+        ));
+    }
+
+    let mut bytes = Vec::with_capacity(3 * TABLE_LENGTH + 4);
+    for entry in palette.iter() {
+        let colour = entry.colour();
+        bytes.extend_from_slice(&[colour.r, colour.g, colour.b]);
+    }
+    bytes.resize(3 * TABLE_LENGTH, 0);
+    bytes.extend_from_slice(&(palette.len() as u16).to_be_bytes());
+    bytes.extend_from_slice(&NO_TRANSPARENCY.to_be_bytes());
+
+    Ok(bytes)
+}
+
+/// Read an ACT file into a palette
+///
+/// Colours are named by their hex code, e.g. `#4682B4`, and each entry of the
+/// table is kept, even where a colour is repeated. Without a count of the
+/// colours in use all 256 entries are read.
+///
+/// ## Example
+///
+/// ```
+/// # use named_colour::adobe::act;
+/// # use named_colour::ext::Green;
+/// # use named_colour::palette::Palette;
+///     let bytes = act::to_act(&Palette::from_family::<Green>()).unwrap();
+///     let imported = act::from_act(&bytes).unwrap();
+///
+///     assert_eq!(Some(Green::SeaGreen.to_rgb()), imported.get("#2E8B57"));
+/// ```
+pub fn from_act(bytes: &[u8]) -> Result<Palette, String> {
+    let count = match bytes.len() {
+        768 => TABLE_LENGTH,
+        772 => u16::from_be_bytes([bytes[768], bytes[769]]) as usize,
+        length => return Err(format!("Invalid ACT file: unexpected length {length}")),
+    };
+    if count > TABLE_LENGTH {
+        return Err(format!("Invalid ACT file: colour count {count}"));
+    }
+
+    let mut palette = Palette::new();
+    for rgb in bytes[..3 * count].chunks_exact(3) {
+        let colour = Rgb::new(rgb[0], rgb[1], rgb[2]);
+        palette.push(&colour.as_hex(), colour);
+    }
+
+    Ok(palette)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Red, green and blue followed by 253 unused entries
+    fn fixture() -> Vec<u8> {
+        let mut bytes = vec![0xFF, 0x00, 0x00, 0x00, 0x80, 0x00, 0x00, 0x00, 0xFF];
+        bytes.resize(768, 0);
+        bytes.extend_from_slice(&[0x00, 0x03, 0xFF, 0xFF]);
+        bytes
+    }
+
+    #[test]
+    fn test_from_fixture() {
+        let palette = from_act(&fixture()).unwrap();
+
+        assert_eq!(3, palette.len());
+        assert_eq!(Some(Rgb::new(0, 128, 0)), palette.get("#008000"));
+        assert_eq!(
+            "#0000FF",
+            palette.nearest(Rgb::new(10, 10, 200)).unwrap().name()
+        );
+    }
+
+    #[test]
+    fn test_round_trip_fixture() {
+        let palette = from_act(&fixture()).unwrap();
+
+        assert_eq!(fixture(), to_act(&palette).unwrap());
+    }
+
+    #[test]
+    fn test_from_table_without_count() {
+        let palette = from_act(&fixture()[..768]).unwrap();
+
+        // The unused entries are all black
+        assert_eq!(TABLE_LENGTH, palette.len());
+        assert_eq!(
+            TABLE_LENGTH - 3,
+            palette
+                .iter()
+                .filter(|entry| entry.name() == "#000000")
+                .count()
+        );
+    }
+
+    #[test]
+    fn test_to_act_too_many_colours() {
+        let mut palette = Palette::new();
+        for value in 0..=256_u16 {
+            palette.push(&value.to_string(), Rgb::new(0, 0, 0));
+        }
+
+        assert!(to_act(&palette).is_err());
+    }
+
+    #[test]
+    fn test_invalid() {
+        assert!(from_act(&[0; 767]).is_err());
+        let mut bytes = fixture();
+        bytes[768] = 0x01;
+        assert!(from_act(&bytes).is_err());
+    }
+}
@@ -0,0 +1,331 @@
+//! Adobe Swatch Exchange (`.ase`) files
+//!
+//! An ASE file holds colour entries, optionally collected in named groups.
+//! Colours are written as RGB entries. RGB and grey entries can be read; other
+//! colour models (CMYK and Lab) are reported as errors.
+//!
+
+use rgb::Rgb;
+
+use super::{encode_utf16, fit, Reader};
+use crate::{palette::Palette, ToHex};
+
+const FORMAT: &str = "ASE";
+const SIGNATURE: &[u8] = b"ASEF";
+const VERSION: (u16, u16) = (1, 0);
+
+const GROUP_START: u16 = 0xC001;
+const GROUP_END: u16 = 0xC002;
+const COLOUR_ENTRY: u16 = 0x0001;
+
+const RGB_MODEL: &[u8] = b"RGB ";
+const GRAY_MODEL: &[u8] = b"Gray";
+/// Colour type of an entry that is neither global nor spot
+const NORMAL: u16 = 2;
+
+/// Write palettes as an ASE file
+///
+/// A palette with a name is written as a group named after the palette. The
+/// entries of a palette without a name are written outside of any group.
+///
+/// ## Errors
+///
+/// Returns an error if a name is longer than 65535 UTF-16 code units.
+///
+/// ## Example
+///
+/// ```
+/// # use named_colour::adobe::ase;
+/// # use named_colour::ext::Blue;
+/// # use named_colour::palette::Palette;
+///     let mut blues = Palette::from_family::<Blue>();
+///     blues.set_name("Blues");
+///
+///     let bytes = ase::to_ase(&[blues]).unwrap();
+///
+///     assert_eq!(b"ASEF", &bytes[..4]);
+/// ```
+pub fn to_ase(palettes: &[Palette]) -> Result<Vec<u8>, String> {
+    let mut blocks = Vec::new();
+    let mut count = 0;
+
+    for palette in palettes {
+        if let Some(name) = palette.name() {
+            push_block(&mut blocks, GROUP_START, &encode_name(name)?);
+            count += 1;
+        }
+
+        for entry in palette.iter() {
+            let mut block = encode_name(entry.name())?;
+            block.extend_from_slice(RGB_MODEL);
+            let colour = entry.colour();
+            for value in [colour.r, colour.g, colour.b] {
+                block.extend_from_slice(&(value as f32 / 255.0).to_be_bytes());
+            }
+            block.extend_from_slice(&NORMAL.to_be_bytes());
+            push_block(&mut blocks, COLOUR_ENTRY, &block);
+            count += 1;
+        }
+
+        if palette.name().is_some() {
+            push_block(&mut blocks, GROUP_END, &[]);
+            count += 1;
+        }
+    }
+
+    let mut bytes = SIGNATURE.to_vec();
+    bytes.extend_from_slice(&VERSION.0.to_be_bytes());
+    bytes.extend_from_slice(&VERSION.1.to_be_bytes());
+    bytes.extend_from_slice(&fit::<u32>(FORMAT, "blocks", count)?.to_be_bytes());
+    bytes.extend_from_slice(&blocks);
+    Ok(bytes)
+}
+
+/// Encode a name with its length in UTF-16 code units
+fn encode_name(name: &str) -> Result<Vec<u8>, String> {
+    let (length, name) = encode_utf16(name);
+    let mut block = fit::<u16>(FORMAT, "characters", length)?
+        .to_be_bytes()
+        .to_vec();
+    block.extend_from_slice(&name);
+    Ok(block)
+}
+
+fn push_block(bytes: &mut Vec<u8>, block_type: u16, block: &[u8]) {
+    bytes.extend_from_slice(&block_type.to_be_bytes());
+    bytes.extend_from_slice(&(block.len() as u32).to_be_bytes());
+    bytes.extend_from_slice(block);
+}
+
+/// Read an ASE file into a single palette, ignoring groups
+///
+/// Entries without a name are named by their hex code, e.g. `#4682B4`. Every
+/// entry is kept, even where a name is repeated.
+pub fn from_ase(bytes: &[u8]) -> Result<Palette, String> {
+    let mut palette = Palette::new();
+    for group in groups_from_ase(bytes)? {
+        for entry in group.iter() {
+            palette.push(entry.name(), entry.colour());
+        }
+    }
+    Ok(palette)
+}
+
+/// Read the groups of an ASE file
+///
+/// Each group is returned as a palette named after the group. Entries outside
+/// of any group are collected in a palette without a name, which is returned
+/// first. Entries without a name are named by their hex code, e.g. `#4682B4`.
+/// A group that is not ended before the next group starts is ended there.
+pub fn groups_from_ase(bytes: &[u8]) -> Result<Vec<Palette>, String> {
+    let mut reader = Reader::new(FORMAT, bytes);
+
+    if reader.take(4)? != SIGNATURE {
+        return Err(reader.error("missing ASEF signature"));
+    }
+    if reader.u16()? != VERSION.0 {
+        return Err(reader.error("unsupported version"));
+    }
+    reader.u16()?;
+    let count = reader.u32()?;
+
+    let mut ungrouped = Palette::new();
+    let mut groups = Vec::new();
+    let mut group: Option<Palette> = None;
+
+    for _ in 0..count {
+        let block_type = reader.u16()?;
+        let length = reader.u32()? as usize;
+        let mut block = Reader::new(FORMAT, reader.take(length)?);
+
+        match block_type {
+            GROUP_START => {
+                let length = block.u16()? as usize;
+                // A group left open is closed by the next one
+                groups.extend(group.replace(Palette::with_name(&block.utf16(length)?)));
+            }
+            GROUP_END => groups.extend(group.take()),
+            COLOUR_ENTRY => {
+                let (name, colour) = read_colour_entry(&mut block)?;
+                let palette = group.as_mut().unwrap_or(&mut ungrouped);
+                match name.as_str() {
+                    "" => palette.push(&colour.as_hex(), colour),
+                    name => palette.push(name, colour),
+                }
+            }
+            _ => {}
+        }
+    }
+    groups.extend(group);
+
+    if !ungrouped.is_empty() {
+        groups.insert(0, ungrouped);
+    }
+    Ok(groups)
+}
+
+fn read_colour_entry(block: &mut Reader) -> Result<(String, Rgb<u8>), String> {
+    let length = block.u16()? as usize;
+    let name = block.utf16(length)?;
+
+    let colour = match block.take(4)? {
+        RGB_MODEL => Rgb::new(
+            to_u8(block.f32()?),
+            to_u8(block.f32()?),
+            to_u8(block.f32()?),
+        ),
+        GRAY_MODEL => {
+            let grey = to_u8(block.f32()?);
+            Rgb::new(grey, grey, grey)
+        }
+        _ => return Err(block.error("unsupported colour model")),
+    };
+
+    Ok((name, colour))
+}
+
+fn to_u8(value: f32) -> u8 {
+    (value.clamp(0.0, 1.0) * 255.0).round() as u8
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ext::{Black, Blue, Red};
+    use rstest::rstest;
+
+    /// An entry outside of any group followed by a group with one entry
+    #[rustfmt::skip]
+    const FIXTURE: &[u8] = &[
+        b'A', b'S', b'E', b'F', 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04,
+        // Colour entry "White": RGB 1.0 1.0 1.0
+        0x00, 0x01, 0x00, 0x00, 0x00, 0x20,
+        0x00, 0x06, 0x00, b'W', 0x00, b'h', 0x00, b'i', 0x00, b't', 0x00, b'e', 0x00, 0x00,
+        b'R', b'G', b'B', b' ',
+        0x3F, 0x80, 0x00, 0x00, 0x3F, 0x80, 0x00, 0x00, 0x3F, 0x80, 0x00, 0x00,
+        0x00, 0x02,
+        // Group start "Brand"
+        0xC0, 0x01, 0x00, 0x00, 0x00, 0x0E,
+        0x00, 0x06, 0x00, b'B', 0x00, b'r', 0x00, b'a', 0x00, b'n', 0x00, b'd', 0x00, 0x00,
+        // Colour entry "Magenta": RGB 1.0 0.0 1.0
+        0x00, 0x01, 0x00, 0x00, 0x00, 0x24,
+        0x00, 0x08, 0x00, b'M', 0x00, b'a', 0x00, b'g', 0x00, b'e', 0x00, b'n', 0x00, b't',
+        0x00, b'a', 0x00, 0x00,
+        b'R', b'G', b'B', b' ',
+        0x3F, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x3F, 0x80, 0x00, 0x00,
+        0x00, 0x02,
+        // Group end
+        0xC0, 0x02, 0x00, 0x00, 0x00, 0x00,
+    ];
+
+    /// A grey entry without a name
+    #[rustfmt::skip]
+    const GREY_FIXTURE: &[u8] = &[
+        b'A', b'S', b'E', b'F', 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01,
+        0x00, 0x01, 0x00, 0x00, 0x00, 0x0E,
+        0x00, 0x01, 0x00, 0x00,
+        b'G', b'r', b'a', b'y',
+        0x3F, 0x00, 0x00, 0x00,
+        0x00, 0x02,
+    ];
+
+    #[test]
+    fn test_groups_from_fixture() {
+        let groups = groups_from_ase(FIXTURE).unwrap();
+
+        assert_eq!(2, groups.len());
+        assert_eq!(None, groups[0].name());
+        assert_eq!(Some(Rgb::new(255, 255, 255)), groups[0].get("White"));
+        assert_eq!(Some("Brand"), groups[1].name());
+        assert_eq!(Some(Rgb::new(255, 0, 255)), groups[1].get("Magenta"));
+    }
+
+    #[test]
+    fn test_round_trip_fixture() {
+        let groups = groups_from_ase(FIXTURE).unwrap();
+
+        assert_eq!(FIXTURE, to_ase(&groups).unwrap().as_slice());
+    }
+
+    #[test]
+    fn test_from_grey_fixture() {
+        let palette = from_ase(GREY_FIXTURE).unwrap();
+
+        assert_eq!(Some(Rgb::new(128, 128, 128)), palette.get("#808080"));
+    }
+
+    #[test]
+    fn test_round_trip_named_colours() {
+        let mut selection = Palette::from_named([
+            Blue::SteelBlue.into(),
+            Red::Crimson.into(),
+            Black::DimGrey.into(),
+        ]);
+        selection.set_name("Selection");
+        let reds = Palette::from_family::<Red>();
+
+        let bytes = to_ase(&[selection.clone(), reds.clone()]).unwrap();
+        let groups = groups_from_ase(&bytes).unwrap();
+
+        assert_eq!(vec![reds, selection], groups);
+    }
+
+    #[test]
+    fn test_from_ase_lookup() {
+        let palette = from_ase(FIXTURE).unwrap();
+
+        assert_eq!(2, palette.len());
+        assert_eq!(
+            "Magenta",
+            palette.nearest(Rgb::new(250, 10, 240)).unwrap().name()
+        );
+    }
+
+    #[test]
+    fn test_from_ase_keeps_repeated_names() {
+        let mut palette = Palette::new();
+        palette.push("Untitled", Rgb::new(255, 0, 0));
+        palette.push("Untitled", Rgb::new(0, 0, 255));
+
+        assert_eq!(
+            palette,
+            from_ase(&to_ase(&[palette.clone()]).unwrap()).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_groups_from_ase_with_unended_group() {
+        let mut first = Palette::from_named([Red::Crimson.into()]);
+        first.set_name("First");
+        let mut second = Palette::from_named([Blue::SteelBlue.into()]);
+        second.set_name("Second");
+
+        let mut bytes = to_ase(&[first.clone(), second.clone()]).unwrap();
+        let group_end = [0xC0, 0x02, 0x00, 0x00, 0x00, 0x00];
+        let at = bytes.windows(6).position(|w| w == group_end).unwrap();
+        bytes.drain(at..at + 6);
+        bytes[11] -= 1;
+
+        assert_eq!(vec![first, second], groups_from_ase(&bytes).unwrap());
+    }
+
+    #[test]
+    fn test_to_ase_name_too_long() {
+        let mut palette = Palette::new();
+        palette.push(&"x".repeat(u16::MAX as usize), Rgb::new(0, 0, 0));
+
+        assert!(to_ase(&[palette]).is_err());
+    }
+
+    #[rstest]
+    #[case(&[])]
+    #[case(b"ASEX\x00\x01\x00\x00\x00\x00\x00\x00")]
+    #[case(b"ASEF\x00\x02\x00\x00\x00\x00\x00\x00")]
+    #[case(&FIXTURE[..FIXTURE.len() - 1])]
+    #[case(
+        b"ASEF\x00\x01\x00\x00\x00\x00\x00\x01\x00\x01\x00\x00\x00\x0A\x00\x01\x00\x00CMYK\x00\x00"
+    )]
+    fn test_invalid(#[case] bytes: &[u8]) {
+        assert!(groups_from_ase(bytes).is_err());
+    }
+}
@@ -0,0 +1,105 @@
+//! Read and write Adobe swatch and colour table files
+//!
+//! - [`ase`] Adobe Swatch Exchange files shared between Adobe applications
+//! - [`aco`] Photoshop colour swatch files
+//! - [`act`] Adobe colour tables
+//!
+//! Colours are exported from and imported into a runtime
+//! [`Palette`](crate::palette::Palette), so named colours can be exported
+//! with [`Palette::from_family`](crate::palette::Palette::from_family) or
+//! [`Palette::from_named`](crate::palette::Palette::from_named) and imported
+//! palettes support name and nearest lookup.
+//!
+//! ## Example
+//!
+//! ```
+//! use named_colour::adobe::ase;
+//! use named_colour::ext::Purple;
+//! use named_colour::palette::Palette;
+//!
+//! let mut purples = Palette::from_family::<Purple>();
+//! purples.set_name("Purples");
+//!
+//! let bytes = ase::to_ase(&[purples]).unwrap();
+//! let imported = ase::from_ase(&bytes).unwrap();
+//!
+//! assert_eq!(Some(Purple::Orchid.to_rgb()), imported.get("Orchid"));
+//! ```
+//!
+
+pub mod aco;
+pub mod act;
+pub mod ase;
+
+/// Convert a count or length to the integer type a file format stores it as
+fn fit<T: TryFrom<usize>>(format: &str, what: &str, value: usize) -> Result<T, String> {
+    T::try_from(value).map_err(|_| format!("Too many {what} for an {format} file: {value}"))
+}
+
+/// Big-endian reader over the bytes of a file
+struct Reader<'a> {
+    format: &'static str,
+    bytes: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+    fn new(format: &'static str, bytes: &'a [u8]) -> Self {
+        Self { format, bytes }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.bytes.is_empty()
+    }
+
+    fn error(&self, reason: &str) -> String {
+        format!("Invalid {} file: {reason}", self.format)
+    }
+
+    fn take(&mut self, length: usize) -> Result<&'a [u8], String> {
+        if self.bytes.len() < length {
+            return Err(self.error("unexpected end of file"));
+        }
+        let (taken, rest) = self.bytes.split_at(length);
+        self.bytes = rest;
+        Ok(taken)
+    }
+
+    fn u16(&mut self) -> Result<u16, String> {
+        let bytes = self.take(2)?;
+        Ok(u16::from_be_bytes([bytes[0], bytes[1]]))
+    }
+
+    fn u32(&mut self) -> Result<u32, String> {
+        let bytes = self.take(4)?;
+        Ok(u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+    }
+
+    fn f32(&mut self) -> Result<f32, String> {
+        self.u32().map(f32::from_bits)
+    }
+
+    /// Read a null terminated UTF-16 string of `length` code units
+    fn utf16(&mut self, length: usize) -> Result<String, String> {
+        let mut units = Vec::with_capacity(length);
+        for _ in 0..length {
+            units.push(self.u16()?);
+        }
+        if units.last() == Some(&0) {
+            units.pop();
+        }
+        String::from_utf16(&units).map_err(|_| self.error("invalid UTF-16 name"))
+    }
+}
+
+/// Encode a string as big-endian UTF-16 with a null terminator
+///
+/// Returns the number of code units along with the encoded bytes.
+fn encode_utf16(string: &str) -> (usize, Vec<u8>) {
+    let mut bytes = Vec::with_capacity(2 * string.len() + 2);
+    let mut length = 0;
+    for unit in string.encode_utf16().chain([0]) {
+        bytes.extend_from_slice(&unit.to_be_bytes());
+        length += 1;
+    }
+    (length, bytes)
+}
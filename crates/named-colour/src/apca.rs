@@ -0,0 +1,248 @@
+//! APCA lightness contrast, the contrast method of the WCAG 3 draft
+//!
+//! APCA measures the contrast of text on a background as a lightness
+//! contrast, Lc, from about 108 for black on white to about -108 for white on
+//! black. The sign gives the polarity: positive for dark text on a light
+//! background and negative for light text on a dark background. Unlike the
+//! WCAG 2 ratio the order of the colours matters.
+//!
+//! The constants are those of APCA-W3 0.0.98G-4g, and the minimum font sizes
+//! come from the APCA font lookup table for that version. Every function
+//! accepts named colours as well as `Rgb<u8>`.
+//!
+//! ## Example
+//!
+//! ```
+//! use named_colour::apca::{lightness_contrast, min_font_size, polarity, Polarity};
+//! use rgb::Rgb;
+//!
+//! let grey = Rgb::new(0x88, 0x88, 0x88);
+//! let white = Rgb::new(255, 255, 255);
+//!
+//! let lc = lightness_contrast(grey, white);
+//! assert_eq!(63.1, (lc * 10.0).round() / 10.0);
+//! assert_eq!(Polarity::DarkOnLight, polarity(grey, white));
+//!
+//! // Normal weight text with this contrast must be at least 24px
+//! assert_eq!(Some(24.0), min_font_size(lc, 400));
+//! ```
+//!
+
+use rgb::Rgb;
+
+const MAIN_TRC: f64 = 2.4;
+const NORM_BG: f64 = 0.56;
+const NORM_TXT: f64 = 0.57;
+const REV_TXT: f64 = 0.62;
+const REV_BG: f64 = 0.65;
+const BLK_THRS: f64 = 0.022;
+const BLK_CLMP: f64 = 1.414;
+const SCALE: f64 = 1.14;
+const LO_OFFSET: f64 = 0.027;
+const LO_CLIP: f64 = 0.1;
+const DELTA_Y_MIN: f64 = 0.0005;
+
+/// Whether the text is darker or lighter than its background
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Polarity {
+    /// Dark text on a light background, with a positive Lc
+    DarkOnLight,
+    /// Light text on a dark background, with a negative Lc
+    LightOnDark,
+}
+
+/// The APCA lightness contrast, Lc, of text on a background
+///
+/// Returns 0 when the contrast is too low to be measured.
+pub fn lightness_contrast(text: impl Into<Rgb<u8>>, background: impl Into<Rgb<u8>>) -> f64 {
+    let text = screen_luminance(text.into());
+    let background = screen_luminance(background.into());
+
+    if (background - text).abs() < DELTA_Y_MIN {
+        return 0.0;
+    }
+
+    let contrast = if background > text {
+        let sapc = (background.powf(NORM_BG) - text.powf(NORM_TXT)) * SCALE;
+        if sapc < LO_CLIP {
+            0.0
+        } else {
+            sapc - LO_OFFSET
+        }
+    } else {
+        let sapc = (background.powf(REV_BG) - text.powf(REV_TXT)) * SCALE;
+        if sapc > -LO_CLIP {
+            0.0
+        } else {
+            sapc + LO_OFFSET
+        }
+    };
+
+    contrast * 100.0
+}
+
+/// The polarity of text on a background
+pub fn polarity(text: impl Into<Rgb<u8>>, background: impl Into<Rgb<u8>>) -> Polarity {
+    if screen_luminance(text.into()) > screen_luminance(background.into()) {
+        Polarity::LightOnDark
+    } else {
+        Polarity::DarkOnLight
+    }
+}
+
+/// Font weights in the columns of the lookup table
+const WEIGHTS: [u16; 9] = [100, 200, 300, 400, 500, 600, 700, 800, 900];
+
+/// Text is not allowed at this contrast
+const PROHIBITED: f64 = 0.0;
+
+/// Minimum font sizes in px by Lc, in steps of 5 from Lc 0, and weight
+#[rustfmt::skip]
+const FONT_SIZES: [[f64; 9]; 26] = [
+    [0.0; 9],
+    [0.0; 9],
+    [0.0; 9],
+    [0.0; 9],
+    [0.0; 9],
+    [0.0, 0.0, 0.0, 120.0, 120.0, 108.0, 96.0, 96.0, 96.0],
+    [0.0, 0.0, 120.0, 108.0, 108.0, 96.0, 72.0, 72.0, 72.0],
+    [0.0, 120.0, 108.0, 96.0, 72.0, 60.0, 48.0, 48.0, 48.0],
+    [120.0, 108.0, 96.0, 60.0, 48.0, 42.0, 32.0, 32.0, 32.0],
+    [108.0, 96.0, 72.0, 42.0, 32.0, 28.0, 24.0, 24.0, 24.0],
+    [96.0, 72.0, 60.0, 32.0, 28.0, 24.0, 21.0, 21.0, 21.0],
+    [80.0, 60.0, 48.0, 28.0, 24.0, 21.0, 18.0, 18.0, 18.0],
+    [72.0, 48.0, 42.0, 24.0, 21.0, 18.0, 16.0, 16.0, 18.0],
+    [68.0, 46.0, 32.0, 21.75, 19.0, 17.0, 15.0, 16.0, 18.0],
+    [64.0, 44.0, 28.0, 19.5, 18.0, 16.0, 14.5, 16.0, 18.0],
+    [60.0, 42.0, 24.0, 18.0, 16.0, 15.0, 14.0, 16.0, 18.0],
+    [56.0, 38.25, 23.0, 17.25, 15.81, 14.0, 14.0, 16.0, 18.0],
+    [52.0, 34.5, 22.0, 16.5, 15.0, 14.0, 14.0, 16.0, 18.0],
+    [48.0, 32.0, 21.0, 16.0, 14.5, 14.0, 14.0, 16.0, 18.0],
+    [45.0, 28.0, 19.5, 15.5, 14.0, 13.5, 14.0, 16.0, 18.0],
+    [42.0, 26.5, 18.5, 15.0, 14.0, 13.5, 14.0, 16.0, 18.0],
+    [39.0, 25.0, 18.0, 14.0, 14.0, 13.0, 14.0, 16.0, 18.0],
+    [36.0, 24.0, 18.0, 14.0, 13.0, 12.0, 14.0, 16.0, 18.0],
+    [34.0, 22.5, 17.0, 13.5, 12.5, 11.5, 14.0, 16.0, 18.0],
+    [32.0, 21.0, 16.0, 13.0, 12.0, 11.0, 14.0, 16.0, 18.0],
+    [30.0, 20.0, 15.0, 12.0, 11.0, 10.0, 14.0, 16.0, 18.0],
+];
+
+/// The minimum font size in px for text of a weight at a lightness contrast
+///
+/// The polarity is ignored, the Lc is rounded down to a multiple of 5 and the
+/// weight down to a multiple of 100, between 100 and 900. Returns `None` if
+/// text of that weight should not be used at that contrast.
+pub fn min_font_size(lc: f64, weight: u16) -> Option<f64> {
+    let row = ((lc.abs() / 5.0) as usize).min(FONT_SIZES.len() - 1);
+    let column = (weight.clamp(100, 900) / 100 - 1) as usize;
+
+    let size = FONT_SIZES[row][column];
+    (size != PROHIBITED).then_some(size)
+}
+
+/// The lightest font weight that text of a size in px can use at a lightness
+/// contrast
+///
+/// Returns `None` if no weight is allowed at that size.
+pub fn min_font_weight(lc: f64, size: f64) -> Option<u16> {
+    WEIGHTS
+        .into_iter()
+        .find(|&weight| min_font_size(lc, weight).is_some_and(|min| size >= min))
+}
+
+/// Screen luminance with the APCA soft clamp for near black
+fn screen_luminance(rgb: Rgb<u8>) -> f64 {
+    let channel = |c: u8| (c as f64 / 255.0).powf(MAIN_TRC);
+    let y =
+        0.212_672_9 * channel(rgb.r) + 0.715_152_2 * channel(rgb.g) + 0.072_175 * channel(rgb.b);
+
+    if y < BLK_THRS {
+        y + (BLK_THRS - y).powf(BLK_CLMP)
+    } else {
+        y
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    fn hex(hex: &str) -> Rgb<u8> {
+        crate::to_hex::parse_hex(hex).unwrap()
+    }
+
+    // Test data from the APCA-W3 reference implementation
+    #[rstest]
+    #[case("#888", "#fff", 63.056_469_930_209_42)]
+    #[case("#fff", "#888", -68.541_464_366_449_62)]
+    #[case("#000", "#aaa", 58.146_262_578_561_33)]
+    #[case("#aaa", "#000", -56.241_133_368_397_42)]
+    #[case("#123", "#def", 91.668_308_114_816_31)]
+    #[case("#def", "#123", -93.067_700_494_842_75)]
+    #[case("#123", "#444", 8.323_261_369_573_93)]
+    #[case("#444", "#123", -7.526_878_460_278_154)]
+    fn test_lightness_contrast(
+        #[case] text: &str,
+        #[case] background: &str,
+        #[case] expected: f64,
+    ) {
+        let lc = lightness_contrast(hex(text), hex(background));
+
+        assert!((expected - lc).abs() < 0.000_001, "{lc}");
+    }
+
+    #[rstest]
+    #[case("#000", "#fff", 106.0)]
+    #[case("#fff", "#000", -107.9)]
+    #[case("#777", "#777", 0.0)]
+    #[case("#fff", "#fefefe", 0.0)]
+    fn test_extremes(#[case] text: &str, #[case] background: &str, #[case] expected: f64) {
+        let lc = lightness_contrast(hex(text), hex(background));
+
+        assert_eq!(expected, (lc * 10.0).round() / 10.0);
+    }
+
+    #[rstest]
+    #[case("#000", "#fff", Polarity::DarkOnLight)]
+    #[case("#fff", "#000", Polarity::LightOnDark)]
+    #[case("#def", "#123", Polarity::LightOnDark)]
+    fn test_polarity(#[case] text: &str, #[case] background: &str, #[case] expected: Polarity) {
+        assert_eq!(expected, polarity(hex(text), hex(background)));
+    }
+
+    #[rstest]
+    #[case(90.0, 400, Some(16.0))]
+    #[case(-90.0, 400, Some(16.0))]
+    #[case(92.0, 400, Some(16.0))]
+    #[case(75.0, 700, Some(14.0))]
+    #[case(60.0, 400, Some(24.0))]
+    #[case(45.0, 100, Some(108.0))]
+    #[case(30.0, 200, None)]
+    #[case(10.0, 900, None)]
+    #[case(140.0, 400, Some(12.0))]
+    #[case(90.0, 0, Some(48.0))]
+    #[case(90.0, 1000, Some(18.0))]
+    fn test_min_font_size(#[case] lc: f64, #[case] weight: u16, #[case] expected: Option<f64>) {
+        assert_eq!(expected, min_font_size(lc, weight));
+    }
+
+    #[rstest]
+    #[case(90.0, 16.0, Some(400))]
+    #[case(90.0, 48.0, Some(100))]
+    #[case(60.0, 16.0, Some(700))]
+    #[case(60.0, 12.0, None)]
+    #[case(10.0, 200.0, None)]
+    fn test_min_font_weight(#[case] lc: f64, #[case] size: f64, #[case] expected: Option<u16>) {
+        assert_eq!(expected, min_font_weight(lc, size));
+    }
+
+    #[cfg(feature = "extended")]
+    #[test]
+    fn test_named_colours() {
+        use crate::ext::{Black, White};
+
+        assert!(lightness_contrast(Black::Black, White::White) > 100.0);
+        assert_eq!(Polarity::LightOnDark, polarity(White::White, Black::Black));
+    }
+}
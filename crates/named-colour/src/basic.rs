@@ -1,181 +1,39 @@
-use std::{fmt, str::FromStr};
-
-use rgb::Rgb;
-
-use crate::Prefix;
-
-/// 16 basic colours with 18 names!
-#[allow(missing_docs)]
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-pub enum Basic {
-    Black,
-    White,
-    Red,
-    Lime,
-    Blue,
-    Yellow,
-    /// Alternate name for Aqua
-    Cyan,
-    /// Alternate name for Cyan
-    Aqua,
-    /// Alternate name for Fuchsia
-    Magenta,
-    /// Alternate name for Magenta
-    Fuchsia,
-    Silver,
-    Gray,
-    Maroon,
-    Olive,
-    Green,
-    Purple,
-    Teal,
-    Navy,
-}
-
-impl fmt::Display for Basic {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match self {
-            Basic::Black => write!(f, "#000000"),
-            Basic::White => write!(f, "#FFFFFF"),
-            Basic::Red => write!(f, "#FF0000"),
-            Basic::Lime => write!(f, "#00FF00"),
-            Basic::Blue => write!(f, "#0000FF"),
-            Basic::Yellow => write!(f, "#FFFF00"),
-            Basic::Cyan => write!(f, "#00FFFF"),
-            Basic::Aqua => write!(f, "#00FFFF"),
-            Basic::Magenta => write!(f, "#FF00FF"),
-            Basic::Fuchsia => write!(f, "#FF00FF"),
-            Basic::Silver => write!(f, "#C0C0C0"),
-            Basic::Gray => write!(f, "#808080"),
-            Basic::Maroon => write!(f, "#800000"),
-            Basic::Olive => write!(f, "#808000"),
-            Basic::Green => write!(f, "#008000"),
-            Basic::Purple => write!(f, "#800080"),
-            Basic::Teal => write!(f, "#008080"),
-            Basic::Navy => write!(f, "#000080"),
-        }
-    }
-}
-
-impl Basic {
-    /// Display the colour name as an RGB Tuple
-    ///
-    /// ## Example
-    ///
-    ///```
-    /// # use named_colour::Basic;
-    /// # fn main() {
-    ///    let rgb_colour = Basic::Black.to_rgb();
-    ///    let string = rgb_colour.to_string();
-    ///    assert_eq!("rgb(0,0,0)", string);
-    ///
-    ///  # }
-    ///```
-    pub fn to_rgb(&self) -> Rgb<u8> {
-        let colour = match self {
-            Basic::Black => "#000000",
-            Basic::White => "#FFFFFF",
-            Basic::Red => "#FF0000",
-            Basic::Lime => "#00FF00",
-            Basic::Blue => "#0000FF",
-            Basic::Yellow => "#FFFF00",
-            Basic::Cyan => "#00FFFF",
-            Basic::Aqua => "#00FFFF",
-            Basic::Magenta => "#FF00FF",
-            Basic::Fuchsia => "#FF00FF",
-            Basic::Silver => "#C0C0C0",
-            Basic::Gray => "#808080",
-            Basic::Maroon => "#800000",
-            Basic::Olive => "#808000",
-            Basic::Green => "#008000",
-            Basic::Purple => "#800080",
-            Basic::Teal => "#008080",
-            Basic::Navy => "#000080",
-        };
-
-        let r: u8 = u8::from_str_radix(&colour[1..3], 16).unwrap();
-        let g: u8 = u8::from_str_radix(&colour[3..5], 16).unwrap();
-        let b: u8 = u8::from_str_radix(&colour[5..7], 16).unwrap();
-
-        Rgb::new(r, g, b)
-    }
-
-    /// Display the colour name as an RGB Tuple
-    ///
-    /// ## Example
-    ///
-    ///```
-    /// # use named_colour::Basic;
-    /// # use named_colour::Prefix;
-    ///     let colour = Basic::Black;
-    ///
-    ///     assert_eq!("#000000", colour.to_hex_triplet(Prefix::Hash));
-    ///
-    ///```
-    pub fn to_hex_triplet(&self, prefix: Prefix) -> String {
-        let rgb = self.to_rgb();
-
-        let prefix = match prefix {
-            Prefix::Hash => "#",
-            Prefix::None => "",
-        };
-
-        format!("{}{:02X}{:02X}{:02X}", prefix, rgb.r, rgb.g, rgb.b)
-    }
-
-    /// Parse a colour from a string
-    ///
-    /// ## Example
-    ///
-    ///```
-    /// # use named_colour::Basic;
-    /// # fn example() {
-    ///    let colour = Basic::parse("#000000");
-    ///    assert_eq!(Some(Basic::Black), colour);
-    ///
-    /// # }
-    ///```  
-    ///
-    pub fn parse(name: &str) -> Option<Basic> {
-        match name.to_lowercase().as_str() {
-            "#000000" | "000000" | "black" => Some(Basic::Black),
-            "#ffffff" | "ffffff" | "white" => Some(Basic::White),
-            "#ff0000" | "ff0000" | "red" => Some(Basic::Red),
-            "#00ff00" | "00ff00" | "lime" => Some(Basic::Lime),
-            "#0000ff" | "0000ff" | "blue" => Some(Basic::Blue),
-            "#ffff00" | "ffff00" | "yellow" => Some(Basic::Yellow),
-            "cyan" => Some(Basic::Cyan),
-            "#00ffff" | "00ffff" | "aqua" => Some(Basic::Aqua),
-            "#ff00ff" | "ff00ff" | "magenta" => Some(Basic::Magenta),
-            "fuchsia" => Some(Basic::Fuchsia),
-            "#c0c0c0" | "c0c0c0" | "silver" => Some(Basic::Silver),
-            "#808080" | "808080" | "gray" => Some(Basic::Gray),
-            "#800000" | "800000" | "maroon" => Some(Basic::Maroon),
-            "#808000" | "808000" | "olive" => Some(Basic::Olive),
-            "#008000" | "008000" | "green" => Some(Basic::Green),
-            "#800080" | "800080" | "purple" => Some(Basic::Purple),
-            "#008080" | "008080" | "teal" => Some(Basic::Teal),
-            "#000080" | "000080" | "navy" => Some(Basic::Navy),
-            _ => None,
-        }
-    }
-}
-
-impl FromStr for Basic {
-    type Err = String;
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match Basic::parse(s) {
-            Some(colour) => Ok(colour),
-            None => Err(format!("Invalid Colour: {s}")),
-        }
+colour_table! {
+    /// 16 basic colours with 18 names!
+    pub enum Basic {
+        Black = "#000000",
+        White = "#FFFFFF",
+        Red = "#FF0000",
+        Lime = "#00FF00",
+        Blue = "#0000FF",
+        Yellow = "#FFFF00",
+        /// Alternate name for Aqua
+        Cyan = "#00FFFF" alias Aqua,
+        /// Alternate name for Cyan
+        Aqua = "#00FFFF",
+        /// Alternate name for Fuchsia
+        Magenta = "#FF00FF",
+        /// Alternate name for Magenta
+        Fuchsia = "#FF00FF" alias Magenta,
+        Silver = "#C0C0C0",
+        Gray = "#808080",
+        Maroon = "#800000",
+        Olive = "#808000",
+        Green = "#008000",
+        Purple = "#800080",
+        Teal = "#008080",
+        Navy = "#000080",
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use std::str::FromStr;
+
     use rstest::rstest;
 
     use super::*;
+    use crate::Prefix;
 
     #[rstest]
     #[case(Basic::Aqua, "rgb(0,255,255)")]
@@ -294,4 +152,39 @@ mod tests {
     fn test_parse(#[case] input: &str, #[case] expected: Basic) {
         assert_eq!(expected, Basic::from_str(input).unwrap())
     }
+
+    #[test]
+    fn test_iter() {
+        assert_eq!(18, Basic::ALL.len());
+        assert!(Basic::iter().eq(Basic::TABLE.iter().map(|row| row.colour)));
+    }
+
+    #[test]
+    fn test_table_round_trip() {
+        crate::table::assert_round_trip(Basic::TABLE);
+    }
+
+    #[rstest]
+    #[case(Basic::Cyan, Basic::Aqua)]
+    #[case(Basic::Fuchsia, Basic::Magenta)]
+    fn test_alias_hex_parses_to_canonical(#[case] alias: Basic, #[case] canonical: Basic) {
+        assert_eq!(Some(canonical), Basic::parse(&alias.to_string()));
+        assert_eq!(Some(alias), Basic::parse(alias.name()));
+    }
+
+    #[rstest]
+    #[case(Basic::Cyan, Basic::Aqua)]
+    #[case(Basic::Fuchsia, Basic::Magenta)]
+    fn test_alias_model(#[case] alias: Basic, #[case] canonical: Basic) {
+        assert_eq!(canonical, alias.canonical());
+        assert_eq!(canonical, canonical.canonical());
+        assert!(alias.is_alias());
+        assert!(!canonical.is_alias());
+        assert!(alias.is_alias_of(&canonical));
+        assert!(!canonical.is_alias_of(&alias));
+        assert!(alias.same_colour(&canonical));
+        assert!(!alias.same_colour(&Basic::Navy));
+        assert_eq!(vec![alias], canonical.aliases().collect::<Vec<_>>());
+        assert_eq!(vec![canonical], alias.aliases().collect::<Vec<_>>());
+    }
 }
@@ -0,0 +1,356 @@
+//! Blend modes and alpha compositing
+//!
+//! The separable blend modes of the W3C Compositing and Blending Level 1
+//! specification, with Porter-Duff source-over compositing. Compositing is
+//! calculated with premultiplied alpha on the gamma encoded sRGB channels, as
+//! browsers do, and only the final result is rounded. Opaque colours can be
+//! given an alpha with [`with_alpha`], which accepts named colours as well as
+//! `Rgb<u8>`.
+//!
+//! ## Example
+//!
+//! ```
+//! use named_colour::blend::{blend, source_over, with_alpha, BlendMode};
+//! use rgb::{Rgb, Rgba};
+//!
+//! let ivory = Rgb::new(255, 255, 240);
+//!
+//! // Black at 40% over ivory
+//! let overlay = source_over(with_alpha(Rgb::new(0, 0, 0), 0.4), with_alpha(ivory, 1.0));
+//! assert_eq!(Rgba::new(153, 153, 144, 255), overlay);
+//!
+//! let multiplied = blend(Rgb::new(255, 128, 0), Rgb::new(128, 128, 128), BlendMode::Multiply);
+//! assert_eq!(Rgb::new(128, 64, 0), multiplied);
+//! ```
+//!
+
+use rgb::{Rgb, Rgba};
+
+/// A separable blend mode, which mixes the source and backdrop colours
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum BlendMode {
+    /// The source colour
+    #[default]
+    Normal,
+    /// The product of the colours, which is never lighter than either
+    Multiply,
+    /// The inverse of the product of the inverses, which is never darker
+    Screen,
+    /// Multiply or screen, depending on the backdrop
+    Overlay,
+    /// The darker of the colours
+    Darken,
+    /// The lighter of the colours
+    Lighten,
+    /// Brighten the backdrop to reflect the source
+    ColourDodge,
+    /// Darken the backdrop to reflect the source
+    ColourBurn,
+    /// Multiply or screen, depending on the source
+    HardLight,
+    /// Darken or lighten, depending on the source
+    SoftLight,
+    /// The difference between the colours
+    Difference,
+    /// Like difference, but with lower contrast
+    Exclusion,
+}
+
+impl BlendMode {
+    /// Every blend mode
+    pub const ALL: &'static [Self] = &[
+        Self::Normal,
+        Self::Multiply,
+        Self::Screen,
+        Self::Overlay,
+        Self::Darken,
+        Self::Lighten,
+        Self::ColourDodge,
+        Self::ColourBurn,
+        Self::HardLight,
+        Self::SoftLight,
+        Self::Difference,
+        Self::Exclusion,
+    ];
+
+    /// The CSS `mix-blend-mode` keyword
+    pub fn css_name(&self) -> &'static str {
+        match self {
+            Self::Normal => "normal",
+            Self::Multiply => "multiply",
+            Self::Screen => "screen",
+            Self::Overlay => "overlay",
+            Self::Darken => "darken",
+            Self::Lighten => "lighten",
+            Self::ColourDodge => "color-dodge",
+            Self::ColourBurn => "color-burn",
+            Self::HardLight => "hard-light",
+            Self::SoftLight => "soft-light",
+            Self::Difference => "difference",
+            Self::Exclusion => "exclusion",
+        }
+    }
+
+    /// Blend one channel of the backdrop and source, each from 0 to 1
+    fn channel(&self, backdrop: f64, source: f64) -> f64 {
+        let (cb, cs) = (backdrop, source);
+        match self {
+            Self::Normal => cs,
+            Self::Multiply => cb * cs,
+            Self::Screen => screen(cb, cs),
+            Self::Overlay => Self::HardLight.channel(cs, cb),
+            Self::Darken => cb.min(cs),
+            Self::Lighten => cb.max(cs),
+            Self::ColourDodge => {
+                if cb == 0.0 {
+                    0.0
+                } else if cs == 1.0 {
+                    1.0
+                } else {
+                    (cb / (1.0 - cs)).min(1.0)
+                }
+            }
+            Self::ColourBurn => {
+                if cb == 1.0 {
+                    1.0
+                } else if cs == 0.0 {
+                    0.0
+                } else {
+                    1.0 - ((1.0 - cb) / cs).min(1.0)
+                }
+            }
+            Self::HardLight => {
+                if cs <= 0.5 {
+                    cb * 2.0 * cs
+                } else {
+                    screen(cb, 2.0 * cs - 1.0)
+                }
+            }
+            Self::SoftLight => {
+                if cs <= 0.5 {
+                    cb - (1.0 - 2.0 * cs) * cb * (1.0 - cb)
+                } else {
+                    let d = if cb <= 0.25 {
+                        ((16.0 * cb - 12.0) * cb + 4.0) * cb
+                    } else {
+                        cb.sqrt()
+                    };
+                    cb + (2.0 * cs - 1.0) * (d - cb)
+                }
+            }
+            Self::Difference => (cb - cs).abs(),
+            Self::Exclusion => cb + cs - 2.0 * cb * cs,
+        }
+    }
+}
+
+/// Give a colour an alpha from 0 (transparent) to 1 (opaque)
+pub fn with_alpha(colour: impl Into<Rgb<u8>>, alpha: f64) -> Rgba<u8> {
+    let rgb = colour.into();
+    Rgba::new(rgb.r, rgb.g, rgb.b, to_channel(alpha))
+}
+
+/// Blend an opaque source colour with an opaque backdrop
+pub fn blend(source: impl Into<Rgb<u8>>, backdrop: impl Into<Rgb<u8>>, mode: BlendMode) -> Rgb<u8> {
+    let (source, backdrop) = (source.into(), backdrop.into());
+    let channel = |s: u8, b: u8| to_channel(mode.channel(from_channel(b), from_channel(s)));
+
+    Rgb::new(
+        channel(source.r, backdrop.r),
+        channel(source.g, backdrop.g),
+        channel(source.b, backdrop.b),
+    )
+}
+
+/// Composite a source over a backdrop with the Porter-Duff source-over
+/// operator
+pub fn source_over(source: Rgba<u8>, backdrop: Rgba<u8>) -> Rgba<u8> {
+    composite(source, backdrop, BlendMode::Normal)
+}
+
+/// Blend a source with a backdrop and composite the result over the backdrop
+///
+/// Where the backdrop is transparent the source shows unblended, and where
+/// the source is transparent the backdrop shows through.
+pub fn composite(source: Rgba<u8>, backdrop: Rgba<u8>, mode: BlendMode) -> Rgba<u8> {
+    let alpha_s = from_channel(source.a);
+    let alpha_b = from_channel(backdrop.a);
+    let alpha_o = alpha_s + alpha_b * (1.0 - alpha_s);
+    if alpha_o == 0.0 {
+        return Rgba::new(0, 0, 0, 0);
+    }
+
+    let channel = |s: u8, b: u8| {
+        let (cs, cb) = (from_channel(s), from_channel(b));
+        let blended = (1.0 - alpha_b) * cs + alpha_b * mode.channel(cb, cs);
+
+        // Source-over with premultiplied colours, then back to straight alpha
+        let premultiplied = alpha_s * blended + alpha_b * cb * (1.0 - alpha_s);
+        to_channel(premultiplied / alpha_o)
+    };
+
+    Rgba::new(
+        channel(source.r, backdrop.r),
+        channel(source.g, backdrop.g),
+        channel(source.b, backdrop.b),
+        to_channel(alpha_o),
+    )
+}
+
+fn screen(cb: f64, cs: f64) -> f64 {
+    cb + cs - cb * cs
+}
+
+fn from_channel(channel: u8) -> f64 {
+    channel as f64 / 255.0
+}
+
+fn to_channel(value: f64) -> u8 {
+    (value.clamp(0.0, 1.0) * 255.0).round() as u8
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    const SOURCE: Rgb<u8> = Rgb::new(255, 128, 0);
+    const BACKDROP: Rgb<u8> = Rgb::new(64, 128, 192);
+
+    #[rstest]
+    #[case(BlendMode::Normal, Rgb::new(255, 128, 0))]
+    #[case(BlendMode::Multiply, Rgb::new(64, 64, 0))]
+    #[case(BlendMode::Screen, Rgb::new(255, 192, 192))]
+    #[case(BlendMode::Overlay, Rgb::new(128, 128, 129))]
+    #[case(BlendMode::Darken, Rgb::new(64, 128, 0))]
+    #[case(BlendMode::Lighten, Rgb::new(255, 128, 192))]
+    #[case(BlendMode::ColourDodge, Rgb::new(255, 255, 192))]
+    #[case(BlendMode::ColourBurn, Rgb::new(64, 2, 0))]
+    #[case(BlendMode::HardLight, Rgb::new(255, 128, 0))]
+    #[case(BlendMode::SoftLight, Rgb::new(128, 128, 145))]
+    #[case(BlendMode::Difference, Rgb::new(191, 0, 192))]
+    #[case(BlendMode::Exclusion, Rgb::new(191, 127, 192))]
+    fn test_blend(#[case] mode: BlendMode, #[case] expected: Rgb<u8>) {
+        assert_eq!(expected, blend(SOURCE, BACKDROP, mode));
+    }
+
+    #[rstest]
+    #[case(BlendMode::Multiply)]
+    #[case(BlendMode::Screen)]
+    #[case(BlendMode::Darken)]
+    #[case(BlendMode::Lighten)]
+    #[case(BlendMode::Difference)]
+    #[case(BlendMode::Exclusion)]
+    fn test_commutative_modes(#[case] mode: BlendMode) {
+        assert_eq!(blend(SOURCE, BACKDROP, mode), blend(BACKDROP, SOURCE, mode));
+    }
+
+    #[test]
+    fn test_identities() {
+        let white = Rgb::new(255, 255, 255);
+        let black = Rgb::new(0, 0, 0);
+
+        assert_eq!(BACKDROP, blend(white, BACKDROP, BlendMode::Multiply));
+        assert_eq!(BACKDROP, blend(black, BACKDROP, BlendMode::Screen));
+        assert_eq!(BACKDROP, blend(black, BACKDROP, BlendMode::Difference));
+        assert_eq!(BACKDROP, blend(black, BACKDROP, BlendMode::ColourDodge));
+        assert_eq!(BACKDROP, blend(white, BACKDROP, BlendMode::ColourBurn));
+    }
+
+    #[rstest]
+    #[case(
+        Rgba::new(0, 0, 0, 102),
+        Rgba::new(255, 255, 240, 255),
+        Rgba::new(153, 153, 144, 255)
+    )]
+    #[case(
+        Rgba::new(255, 0, 0, 128),
+        Rgba::new(0, 0, 255, 255),
+        Rgba::new(128, 0, 127, 255)
+    )]
+    #[case(
+        Rgba::new(255, 0, 0, 128),
+        Rgba::new(0, 0, 255, 128),
+        Rgba::new(170, 0, 85, 192)
+    )]
+    #[case(
+        Rgba::new(255, 0, 0, 0),
+        Rgba::new(0, 0, 255, 128),
+        Rgba::new(0, 0, 255, 128)
+    )]
+    #[case(
+        Rgba::new(255, 0, 0, 128),
+        Rgba::new(0, 0, 255, 0),
+        Rgba::new(255, 0, 0, 128)
+    )]
+    #[case(
+        Rgba::new(255, 0, 0, 0),
+        Rgba::new(0, 0, 255, 0),
+        Rgba::new(0, 0, 0, 0)
+    )]
+    fn test_source_over(
+        #[case] source: Rgba<u8>,
+        #[case] backdrop: Rgba<u8>,
+        #[case] expected: Rgba<u8>,
+    ) {
+        assert_eq!(expected, source_over(source, backdrop));
+    }
+
+    #[test]
+    fn test_composite_opaque_is_blend() {
+        for &mode in BlendMode::ALL {
+            let composited = composite(with_alpha(SOURCE, 1.0), with_alpha(BACKDROP, 1.0), mode);
+
+            assert_eq!(blend(SOURCE, BACKDROP, mode), composited.rgb());
+            assert_eq!(255, composited.a);
+        }
+    }
+
+    #[test]
+    fn test_composite_over_transparent_is_unblended() {
+        let source = with_alpha(SOURCE, 0.5);
+        let backdrop = with_alpha(BACKDROP, 0.0);
+
+        assert_eq!(source, composite(source, backdrop, BlendMode::Multiply));
+    }
+
+    #[test]
+    fn test_composite_partial_alpha() {
+        let source = with_alpha(SOURCE, 0.5);
+        let backdrop = with_alpha(BACKDROP, 1.0);
+
+        assert_eq!(
+            Rgba::new(64, 96, 96, 255),
+            composite(source, backdrop, BlendMode::Multiply)
+        );
+    }
+
+    #[rstest]
+    #[case(0.0, 0)]
+    #[case(0.4, 102)]
+    #[case(1.0, 255)]
+    #[case(1.5, 255)]
+    #[case(-1.0, 0)]
+    fn test_with_alpha(#[case] alpha: f64, #[case] expected: u8) {
+        assert_eq!(expected, with_alpha(SOURCE, alpha).a);
+    }
+
+    #[test]
+    fn test_css_names_are_unique() {
+        let mut names: Vec<&str> = BlendMode::ALL.iter().map(|mode| mode.css_name()).collect();
+        names.sort_unstable();
+        names.dedup();
+
+        assert_eq!(BlendMode::ALL.len(), names.len());
+    }
+
+    #[cfg(feature = "extended")]
+    #[test]
+    fn test_named_colours() {
+        use crate::ext::{Black, White};
+
+        let overlay = source_over(with_alpha(Black::Black, 0.4), with_alpha(White::Ivory, 1.0));
+
+        assert_eq!(Rgba::new(153, 153, 144, 255), overlay);
+    }
+}
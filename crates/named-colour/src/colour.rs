@@ -0,0 +1,289 @@
+//! A colour that is either a named colour or a custom RGB value
+//!
+
+use std::{fmt, str::FromStr};
+
+use rgb::Rgb;
+#[cfg(feature = "serde")]
+use serde::{de::Error, Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{
+    ext::{
+        lookup, Black, Blue, Brown, Cyan, Family, Green, NamedColour, Purple, Red, White, Yellow,
+    },
+    to_hex::parse_hex,
+    ToHex,
+};
+
+/// A named colour, or a custom RGB value for colours without a name
+///
+/// This is the type to use for colours in configuration. It parses any name
+/// or hex code, keeping the exact RGB value, and a custom colour that exactly
+/// matches a named colour is named. Named colours are displayed by name and
+/// custom colours as `#RRGGBB`, and with the `serde` feature serde uses the
+/// same strings.
+///
+/// Names and hex codes are resolved with [`lookup`](crate::ext::lookup), so
+/// `YellowGreen` is the `Green` colour and a hex code gives a canonical colour,
+/// never an alias. A colour whose name is also in an earlier family is
+/// displayed with its family, as `Yellow::YellowGreen`, so that every colour
+/// parses back to itself.
+///
+/// ## Example
+///
+/// ```
+/// # use named_colour::{ext::Blue, Colour};
+/// # use rgb::Rgb;
+///     let named: Colour = "steelblue".parse().unwrap();
+///     let matched: Colour = "#4682B4".parse().unwrap();
+///     let custom: Colour = "#4682B5".parse().unwrap();
+///
+///     assert_eq!(Colour::from(Blue::SteelBlue), named);
+///     assert_eq!(named, matched);
+///     assert_eq!(Colour::Custom(Rgb::new(70, 130, 181)), custom);
+///     assert_eq!("SteelBlue", named.to_string());
+///     assert_eq!("#4682B5", custom.to_string());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Colour {
+    /// A colour from one of the extended colour families
+    Named(NamedColour),
+    /// An RGB value that is not a named colour
+    Custom(Rgb<u8>),
+}
+
+impl Colour {
+    /// Parse a colour from a name or a hex code
+    ///
+    /// Hex codes may be `#RRGGBB` or `#RGB`, with or without the `#`. A name
+    /// may be qualified by its family, as in `Yellow::YellowGreen`.
+    pub fn parse(input: &str) -> Option<Self> {
+        if let Some((family, name)) = input.split_once("::") {
+            return Family::iter()
+                .find(|candidate| candidate.name().eq_ignore_ascii_case(family))?
+                .members()
+                .find(|colour| colour.name().eq_ignore_ascii_case(name))
+                .map(Self::Named);
+        }
+
+        match lookup(input) {
+            Some(named) => Some(Self::Named(named)),
+            None => parse_hex(input).map(Self::from),
+        }
+    }
+
+    /// Return the RGB value of the colour
+    pub const fn to_rgb(&self) -> Rgb<u8> {
+        match self {
+            Self::Named(colour) => colour.to_rgb(),
+            Self::Custom(rgb) => *rgb,
+        }
+    }
+
+    /// Return the named colour, if the colour has a name
+    pub fn named(&self) -> Option<NamedColour> {
+        match self {
+            Self::Named(colour) => Some(*colour),
+            Self::Custom(_) => None,
+        }
+    }
+
+    /// Return the name of the colour, if it has one
+    pub fn name(&self) -> Option<&'static str> {
+        self.named().map(|colour| colour.name())
+    }
+}
+
+impl From<Rgb<u8>> for Colour {
+    /// Name the colour if it exactly matches a named colour
+    fn from(rgb: Rgb<u8>) -> Self {
+        match lookup(&rgb.as_hex()) {
+            Some(named) => Self::Named(named),
+            None => Self::Custom(rgb),
+        }
+    }
+}
+
+impl From<NamedColour> for Colour {
+    fn from(colour: NamedColour) -> Self {
+        Self::Named(colour)
+    }
+}
+
+macro_rules! impl_from_family {
+    ($($family:ident),*) => {
+        $(
+            impl From<$family> for Colour {
+                fn from(colour: $family) -> Self {
+                    Self::Named(colour.into())
+                }
+            }
+        )*
+    };
+}
+
+impl_from_family!(Black, Blue, Brown, Cyan, Green, Purple, Red, White, Yellow);
+
+impl From<Colour> for Rgb<u8> {
+    fn from(colour: Colour) -> Self {
+        colour.to_rgb()
+    }
+}
+
+impl fmt::Display for Colour {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Named(colour) if lookup(colour.name()) == Some(*colour) => {
+                f.write_str(colour.name())
+            }
+            Self::Named(colour) => write!(f, "{}::{}", colour.family(), colour.name()),
+            Self::Custom(rgb) => f.write_str(&rgb.as_hex()),
+        }
+    }
+}
+
+impl FromStr for Colour {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match Self::parse(s) {
+            Some(colour) => Ok(colour),
+            None => Err(format!("Invalid Colour: {s}")),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for Colour {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for Colour {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let colour = String::deserialize(deserializer)?;
+        colour.parse().map_err(D::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ext::all_named_colours;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("DarkOrchid", Colour::Named(NamedColour::Purple(Purple::DarkOrchid)))]
+    #[case("grey", Colour::Named(NamedColour::Black(Black::Grey)))]
+    #[case("#808080", Colour::Named(NamedColour::Black(Black::Gray)))]
+    #[case("9acd32", Colour::Named(NamedColour::Green(Green::YellowGreen)))]
+    #[case("#FFF", Colour::Named(NamedColour::White(White::White)))]
+    #[case("#123456", Colour::Custom(Rgb::new(18, 52, 86)))]
+    #[case("#abc", Colour::Custom(Rgb::new(170, 187, 204)))]
+    #[case(
+        "Yellow::YellowGreen",
+        Colour::Named(NamedColour::Yellow(Yellow::YellowGreen))
+    )]
+    #[case(
+        "green::yellowgreen",
+        Colour::Named(NamedColour::Green(Green::YellowGreen))
+    )]
+    fn test_parse(#[case] input: &str, #[case] expected: Colour) {
+        assert_eq!(Some(expected), Colour::parse(input));
+        assert_eq!(Ok(expected), input.parse::<Colour>());
+    }
+
+    #[rstest]
+    #[case("not a colour")]
+    #[case("#12345")]
+    #[case("")]
+    #[case("Red::YellowGreen")]
+    #[case("Orange::YellowGreen")]
+    fn test_parse_invalid(#[case] input: &str) {
+        assert_eq!(None, Colour::parse(input));
+        assert_eq!(
+            Err(format!("Invalid Colour: {input}")),
+            input.parse::<Colour>()
+        );
+    }
+
+    #[rstest]
+    #[case(Rgb::new(70, 130, 180), Colour::from(Blue::SteelBlue))]
+    #[case(Rgb::new(70, 130, 181), Colour::Custom(Rgb::new(70, 130, 181)))]
+    fn test_from_rgb(#[case] rgb: Rgb<u8>, #[case] expected: Colour) {
+        assert_eq!(expected, Colour::from(rgb));
+        assert_eq!(rgb, expected.to_rgb());
+    }
+
+    #[rstest]
+    #[case(Colour::from(Blue::SteelBlue), "SteelBlue", Some("SteelBlue"))]
+    #[case(Colour::from(Black::Grey), "Grey", Some("Grey"))]
+    #[case(Colour::from(Green::YellowGreen), "YellowGreen", Some("YellowGreen"))]
+    #[case(
+        Colour::from(Yellow::YellowGreen),
+        "Yellow::YellowGreen",
+        Some("YellowGreen")
+    )]
+    #[case(Colour::Custom(Rgb::new(18, 52, 86)), "#123456", None)]
+    fn test_display(#[case] colour: Colour, #[case] expected: &str, #[case] name: Option<&str>) {
+        assert_eq!(expected, colour.to_string());
+        assert_eq!(name, colour.name());
+        assert_eq!(colour, expected.parse().unwrap());
+    }
+
+    #[test]
+    fn test_every_named_colour_round_trips() {
+        for colour in all_named_colours().map(Colour::Named) {
+            assert_eq!(Ok(colour), colour.to_string().parse());
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Theme {
+        background: Colour,
+        accent: Colour,
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_json() {
+        let theme = Theme {
+            background: Colour::from(White::GhostWhite),
+            accent: Colour::Custom(Rgb::new(18, 52, 86)),
+        };
+        let json = serde_json::to_string(&theme).unwrap();
+
+        assert_eq!(r##"{"background":"GhostWhite","accent":"#123456"}"##, json);
+        assert_eq!(theme, serde_json::from_str(&json).unwrap());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_json_keeps_family() {
+        let colour = Colour::from(Yellow::YellowGreen);
+        let json = serde_json::to_string(&colour).unwrap();
+
+        assert_eq!(r#""Yellow::YellowGreen""#, json);
+        assert_eq!(colour, serde_json::from_str(&json).unwrap());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_deserialize_any_name_or_hex() {
+        let theme: Theme =
+            serde_json::from_str(r##"{"background":"navy","accent":"#f80"}"##).unwrap();
+
+        assert_eq!(Colour::from(Blue::Navy), theme.background);
+        assert_eq!(Colour::Custom(Rgb::new(255, 136, 0)), theme.accent);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_deserialize_invalid() {
+        let error = serde_json::from_str::<Colour>(r#""not a colour""#).unwrap_err();
+
+        assert!(error.to_string().contains("Invalid Colour: not a colour"));
+    }
+}
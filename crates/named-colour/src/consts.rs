@@ -0,0 +1,71 @@
+//! RGB constants for every named colour
+//!
+//! Each constant is the value of `to_rgb()` for a named colour, evaluated when
+//! the crate is compiled. Constants are named after the colour in screaming
+//! snake case, e.g. `Red::Crimson` is `CRIMSON`. They can be used in `const`
+//! and `static` items such as embedded lookup tables without allocating.
+//!
+//! A name found in more than one family, such as `YellowGreen`, is defined once.
+//!
+//! ## Example
+//!
+//! ```
+//! use named_colour::consts::{NAVY, SILVER, TEAL};
+//! use named_colour::RGB8;
+//!
+//! static STATUS_COLOURS: [RGB8; 3] = [NAVY, SILVER, TEAL];
+//!
+//! assert_eq!(RGB8::new(0, 0, 128), STATUS_COLOURS[0]);
+//! ```
+//!
+
+#[cfg(not(feature = "extended"))]
+pub use crate::basic::consts::*;
+#[cfg(feature = "extended")]
+pub use crate::ext::{
+    black::consts::*, blue::consts::*, brown::consts::*, cyan::consts::*, green::consts::*,
+    purple::consts::*, red::consts::*, white::consts::*, yellow::consts::*,
+};
+
+// `Green::YellowGreen` and `Yellow::YellowGreen` share a name and a value
+#[cfg(feature = "extended")]
+pub use crate::ext::green::consts::YELLOW_GREEN;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::RGB8;
+    #[cfg(feature = "extended")]
+    use crate::{Blue, Green, Red, Yellow};
+    use rstest::rstest;
+
+    const LOOKUP: [(&str, RGB8); 3] = [("navy", NAVY), ("silver", SILVER), ("teal", TEAL)];
+
+    #[rstest]
+    #[case(BLACK, RGB8::new(0, 0, 0))]
+    #[case(FUCHSIA, RGB8::new(255, 0, 255))]
+    #[case(GRAY, RGB8::new(128, 128, 128))]
+    #[case(LIME, RGB8::new(0, 255, 0))]
+    #[case(WHITE, RGB8::new(255, 255, 255))]
+    fn test_consts(#[case] colour: RGB8, #[case] expected: RGB8) {
+        assert_eq!(expected, colour);
+    }
+
+    #[test]
+    fn test_const_lookup_table() {
+        let teal = LOOKUP.iter().find(|(name, _)| *name == "teal");
+
+        assert_eq!(Some(RGB8::new(0, 128, 128)), teal.map(|(_, rgb)| *rgb));
+    }
+
+    #[cfg(feature = "extended")]
+    #[rstest]
+    #[case(CRIMSON, Red::Crimson)]
+    #[case(STEEL_BLUE, Blue::SteelBlue)]
+    #[case(GOLDENROD, Yellow::Goldenrod)]
+    #[case(YELLOW_GREEN, Green::YellowGreen)]
+    #[case(YELLOW_GREEN, Yellow::YellowGreen)]
+    fn test_extended_consts(#[case] colour: RGB8, #[case] named: impl Into<RGB8>) {
+        assert_eq!(named.into(), colour);
+    }
+}
@@ -0,0 +1,235 @@
+//! Contrast between colours
+//!
+//! Relative luminance and contrast ratio are calculated as defined by WCAG 2,
+//! with checks against the AA and AAA success criteria for normal and large
+//! text. Every function accepts named colours as well as `Rgb<u8>`.
+//!
+//! ## Example
+//!
+//! ```
+//! use named_colour::contrast::{contrast_ratio, meets, readable_text_colour, Level, TextSize};
+//! use rgb::Rgb;
+//!
+//! let ratio = contrast_ratio(Rgb::new(0, 0, 0), Rgb::new(255, 255, 255));
+//! assert_eq!(21.0, ratio);
+//!
+//! let grey = Rgb::new(118, 118, 118);
+//! let white = Rgb::new(255, 255, 255);
+//! assert!(meets(grey, white, Level::AA, TextSize::Normal));
+//! assert!(!meets(grey, white, Level::AAA, TextSize::Normal));
+//!
+//! assert_eq!(Rgb::new(255, 255, 255), readable_text_colour(Rgb::new(0, 0, 128)));
+//! ```
+//!
+
+use rgb::Rgb;
+
+use crate::lab::linear;
+
+/// The relative luminance of a colour, from 0 for black to 1 for white
+///
+/// Accepts named colours as well as `Rgb<u8>`.
+pub fn relative_luminance(colour: impl Into<Rgb<u8>>) -> f64 {
+    let rgb = colour.into();
+    0.2126 * linear(rgb.r) + 0.7152 * linear(rgb.g) + 0.0722 * linear(rgb.b)
+}
+
+/// The WCAG 2 contrast ratio between two colours, from 1 to 21
+///
+/// The ratio is the same whichever colour is given first.
+pub fn contrast_ratio(a: impl Into<Rgb<u8>>, b: impl Into<Rgb<u8>>) -> f64 {
+    let (a, b) = (relative_luminance(a), relative_luminance(b));
+    (a.max(b) + 0.05) / (a.min(b) + 0.05)
+}
+
+/// A WCAG 2 conformance level for contrast
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Level {
+    /// Success criterion 1.4.3, contrast (minimum)
+    AA,
+    /// Success criterion 1.4.6, contrast (enhanced)
+    AAA,
+}
+
+/// The size of text, which sets the contrast it needs
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TextSize {
+    /// Text below the large text size
+    Normal,
+    /// Text of at least 18 point, or 14 point bold
+    Large,
+}
+
+impl Level {
+    /// The minimum contrast ratio for text of the given size
+    pub fn min_ratio(&self, size: TextSize) -> f64 {
+        match (self, size) {
+            (Self::AA, TextSize::Normal) => 4.5,
+            (Self::AA, TextSize::Large) => 3.0,
+            (Self::AAA, TextSize::Normal) => 7.0,
+            (Self::AAA, TextSize::Large) => 4.5,
+        }
+    }
+}
+
+/// Return true if text in one colour on a background of the other meets the
+/// level for the text size
+pub fn meets(
+    text: impl Into<Rgb<u8>>,
+    background: impl Into<Rgb<u8>>,
+    level: Level,
+    size: TextSize,
+) -> bool {
+    contrast_ratio(text, background) >= level.min_ratio(size)
+}
+
+/// The best level met by text in one colour on a background of the other, or
+/// `None` if it fails AA
+pub fn level(
+    text: impl Into<Rgb<u8>>,
+    background: impl Into<Rgb<u8>>,
+    size: TextSize,
+) -> Option<Level> {
+    let ratio = contrast_ratio(text, background);
+    [Level::AAA, Level::AA]
+        .into_iter()
+        .find(|level| ratio >= level.min_ratio(size))
+}
+
+/// Choose black or white text, whichever contrasts more with the background
+pub fn readable_text_colour(background: impl Into<Rgb<u8>>) -> Rgb<u8> {
+    let (black, white) = (Rgb::new(0, 0, 0), Rgb::new(255, 255, 255));
+    let background = background.into();
+
+    if contrast_ratio(black, background) >= contrast_ratio(white, background) {
+        black
+    } else {
+        white
+    }
+}
+
+/// Choose the candidate text colour that contrasts most with the background
+///
+/// Returns `None` if there are no candidates. Where candidates contrast
+/// equally, the first is chosen.
+///
+/// ## Example
+///
+/// ```
+/// # use named_colour::contrast::readable_text_colour_from;
+/// # use rgb::Rgb;
+///     let navy = Rgb::new(0, 0, 128);
+///     let candidates = [Rgb::new(0, 0, 255), Rgb::new(255, 255, 0), Rgb::new(128, 0, 0)];
+///
+///     assert_eq!(Some(Rgb::new(255, 255, 0)), readable_text_colour_from(navy, candidates));
+/// ```
+pub fn readable_text_colour_from<T>(
+    background: impl Into<Rgb<u8>>,
+    candidates: impl IntoIterator<Item = T>,
+) -> Option<T>
+where
+    T: Into<Rgb<u8>> + Copy,
+{
+    let background = background.into();
+
+    candidates
+        .into_iter()
+        .fold(None, |best, candidate| {
+            let ratio = contrast_ratio(candidate, background);
+            match best {
+                Some((_, best_ratio)) if best_ratio >= ratio => best,
+                _ => Some((candidate, ratio)),
+            }
+        })
+        .map(|(candidate, _)| candidate)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(Rgb::new(0, 0, 0), 0.0)]
+    #[case(Rgb::new(255, 255, 255), 1.0)]
+    #[case(Rgb::new(255, 0, 0), 0.2126)]
+    #[case(Rgb::new(128, 128, 128), 0.2159)]
+    fn test_relative_luminance(#[case] colour: Rgb<u8>, #[case] expected: f64) {
+        assert!((expected - relative_luminance(colour)).abs() < 0.0001);
+    }
+
+    #[rstest]
+    #[case(Rgb::new(0, 0, 0), Rgb::new(255, 255, 255), 21.0)]
+    #[case(Rgb::new(255, 255, 255), Rgb::new(0, 0, 0), 21.0)]
+    #[case(Rgb::new(118, 118, 118), Rgb::new(255, 255, 255), 4.54)]
+    #[case(Rgb::new(70, 130, 180), Rgb::new(70, 130, 180), 1.0)]
+    fn test_contrast_ratio(#[case] a: Rgb<u8>, #[case] b: Rgb<u8>, #[case] expected: f64) {
+        assert!((expected - contrast_ratio(a, b)).abs() < 0.01);
+    }
+
+    #[rstest]
+    #[case(Level::AA, TextSize::Normal, 4.5)]
+    #[case(Level::AA, TextSize::Large, 3.0)]
+    #[case(Level::AAA, TextSize::Normal, 7.0)]
+    #[case(Level::AAA, TextSize::Large, 4.5)]
+    fn test_min_ratio(#[case] level: Level, #[case] size: TextSize, #[case] expected: f64) {
+        assert_eq!(expected, level.min_ratio(size));
+    }
+
+    #[rstest]
+    #[case(Rgb::new(0, 0, 0), TextSize::Normal, Some(Level::AAA))]
+    #[case(Rgb::new(118, 118, 118), TextSize::Normal, Some(Level::AA))]
+    #[case(Rgb::new(118, 118, 118), TextSize::Large, Some(Level::AAA))]
+    #[case(Rgb::new(140, 140, 140), TextSize::Normal, None)]
+    #[case(Rgb::new(140, 140, 140), TextSize::Large, Some(Level::AA))]
+    #[case(Rgb::new(200, 200, 200), TextSize::Large, None)]
+    fn test_level(#[case] text: Rgb<u8>, #[case] size: TextSize, #[case] expected: Option<Level>) {
+        let white = Rgb::new(255, 255, 255);
+
+        assert_eq!(expected, level(text, white, size));
+        for candidate in [Level::AA, Level::AAA] {
+            let passes = expected == Some(Level::AAA) || expected == Some(candidate);
+            assert_eq!(passes, meets(text, white, candidate, size));
+        }
+    }
+
+    #[rstest]
+    #[case(Rgb::new(255, 255, 255), Rgb::new(0, 0, 0))]
+    #[case(Rgb::new(255, 255, 0), Rgb::new(0, 0, 0))]
+    #[case(Rgb::new(0, 0, 128), Rgb::new(255, 255, 255))]
+    #[case(Rgb::new(0, 0, 0), Rgb::new(255, 255, 255))]
+    fn test_readable_text_colour(#[case] background: Rgb<u8>, #[case] expected: Rgb<u8>) {
+        assert_eq!(expected, readable_text_colour(background));
+    }
+
+    #[test]
+    fn test_readable_text_colour_from_none() {
+        assert_eq!(
+            None,
+            readable_text_colour_from(Rgb::new(0, 0, 0), Vec::<Rgb<u8>>::new())
+        );
+    }
+
+    #[cfg(feature = "extended")]
+    #[test]
+    fn test_named_colours() {
+        use crate::ext::{Blue, NamedColour, White, Yellow};
+
+        assert!(meets(
+            White::White,
+            Blue::Navy,
+            Level::AAA,
+            TextSize::Normal
+        ));
+        assert_eq!(
+            Some(NamedColour::from(Yellow::Yellow)),
+            readable_text_colour_from(
+                Blue::Navy,
+                [
+                    NamedColour::from(Blue::Blue),
+                    NamedColour::from(Yellow::Yellow)
+                ]
+            )
+        );
+    }
+}
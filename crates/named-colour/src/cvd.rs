@@ -0,0 +1,261 @@
+//! Colour vision deficiency simulation
+//!
+//! Colours are simulated as seen with protanopia, deuteranopia or tritanopia,
+//! where one kind of cone is missing, using the model of Brettel, Viénot and
+//! Mollon (1997) with the sRGB parameters of DaltonLens. The anomalous
+//! trichromacies, where one kind of cone is shifted, are simulated by mixing
+//! the colour with the dichromat simulation in linear RGB by a severity from
+//! 0 (normal vision) to 1 (dichromacy).
+//!
+//! ## Example
+//!
+//! ```
+//! use named_colour::cvd::{indistinguishable_pairs, Deficiency};
+//! use rgb::Rgb;
+//!
+//! let red = Rgb::new(200, 40, 40);
+//! let green = Rgb::new(90, 110, 40);
+//! let blue = Rgb::new(40, 80, 200);
+//!
+//! // Red and green are hard to tell apart without green cones
+//! let pairs = indistinguishable_pairs(&[red, green, blue], Deficiency::Deuteranopia, 10.0);
+//! assert_eq!(vec![(red, green)], pairs);
+//! ```
+//!
+
+use rgb::Rgb;
+
+use crate::lab::{delta_e, encode, linear};
+
+/// A colour vision deficiency
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Deficiency {
+    /// No red (long wavelength) cones
+    Protanopia,
+    /// No green (medium wavelength) cones
+    Deuteranopia,
+    /// No blue (short wavelength) cones
+    Tritanopia,
+    /// Shifted red cones, with a severity from 0 to 1
+    Protanomaly(f64),
+    /// Shifted green cones, with a severity from 0 to 1
+    Deuteranomaly(f64),
+    /// Shifted blue cones, with a severity from 0 to 1
+    Tritanomaly(f64),
+}
+
+/// The Brettel parameters of a dichromacy: the projections on either side
+/// of the plane separating the two half planes, and the normal of the plane
+struct Brettel {
+    first: [[f64; 3]; 3],
+    second: [[f64; 3]; 3],
+    normal: [f64; 3],
+}
+
+const PROTAN: Brettel = Brettel {
+    first: [
+        [0.14510, 1.20165, -0.34675],
+        [0.10447, 0.85316, 0.04237],
+        [0.00429, -0.00603, 1.00174],
+    ],
+    second: [
+        [0.14115, 1.16782, -0.30897],
+        [0.10495, 0.85730, 0.03776],
+        [0.00431, -0.00586, 1.00155],
+    ],
+    normal: [0.00048, 0.00416, -0.00464],
+};
+
+const DEUTAN: Brettel = Brettel {
+    first: [
+        [0.36198, 0.86755, -0.22953],
+        [0.26099, 0.64512, 0.09389],
+        [-0.01975, 0.02686, 0.99289],
+    ],
+    second: [
+        [0.37009, 0.88540, -0.25549],
+        [0.25767, 0.63782, 0.10451],
+        [-0.01950, 0.02741, 0.99209],
+    ],
+    normal: [-0.00293, -0.00645, 0.00938],
+};
+
+const TRITAN: Brettel = Brettel {
+    first: [
+        [1.01354, 0.14268, -0.15622],
+        [-0.01181, 0.87561, 0.13619],
+        [0.07707, 0.81208, 0.11085],
+    ],
+    second: [
+        [0.93337, 0.19999, -0.13336],
+        [0.05809, 0.82565, 0.11626],
+        [-0.37923, 1.13825, 0.24098],
+    ],
+    normal: [0.03960, -0.02831, -0.01129],
+};
+
+impl Deficiency {
+    /// Simulate how a colour is seen with the deficiency
+    pub fn simulate(&self, colour: impl Into<Rgb<u8>>) -> Rgb<u8> {
+        let (brettel, severity) = match *self {
+            Self::Protanopia => (&PROTAN, 1.0),
+            Self::Deuteranopia => (&DEUTAN, 1.0),
+            Self::Tritanopia => (&TRITAN, 1.0),
+            Self::Protanomaly(severity) => (&PROTAN, severity.clamp(0.0, 1.0)),
+            Self::Deuteranomaly(severity) => (&DEUTAN, severity.clamp(0.0, 1.0)),
+            Self::Tritanomaly(severity) => (&TRITAN, severity.clamp(0.0, 1.0)),
+        };
+
+        let rgb = colour.into();
+        let input = [linear(rgb.r), linear(rgb.g), linear(rgb.b)];
+        let side: f64 = input.iter().zip(brettel.normal).map(|(c, n)| c * n).sum();
+        let matrix = if side >= 0.0 {
+            &brettel.first
+        } else {
+            &brettel.second
+        };
+
+        let simulated = |row: usize| {
+            let cvd: f64 = matrix[row].iter().zip(input).map(|(m, c)| m * c).sum();
+            encode(cvd * severity + input[row] * (1.0 - severity))
+        };
+        Rgb::new(simulated(0), simulated(1), simulated(2))
+    }
+}
+
+/// Simulate how a colour is seen with a deficiency
+///
+/// Accepts named colours as well as `Rgb<u8>`.
+pub fn simulate(colour: impl Into<Rgb<u8>>, deficiency: Deficiency) -> Rgb<u8> {
+    deficiency.simulate(colour)
+}
+
+/// Find the pairs of colours that are hard to tell apart with a deficiency
+///
+/// A pair is reported when the CIEDE2000 difference between the simulated
+/// colours is below `min_delta_e`; a value around 10 suits colours that must
+/// be told apart at a glance, such as chart series. Pairs are returned in the
+/// order of the colours given.
+pub fn indistinguishable_pairs<T>(
+    colours: &[T],
+    deficiency: Deficiency,
+    min_delta_e: f64,
+) -> Vec<(T, T)>
+where
+    T: Into<Rgb<u8>> + Copy,
+{
+    let simulated: Vec<Rgb<u8>> = colours
+        .iter()
+        .map(|&colour| deficiency.simulate(colour))
+        .collect();
+
+    let mut pairs = Vec::new();
+    for (i, a) in simulated.iter().enumerate() {
+        for (j, b) in simulated.iter().enumerate().skip(i + 1) {
+            if delta_e(*a, *b) < min_delta_e {
+                pairs.push((colours[i], colours[j]));
+            }
+        }
+    }
+    pairs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(Deficiency::Protanopia)]
+    #[case(Deficiency::Deuteranopia)]
+    #[case(Deficiency::Tritanopia)]
+    #[case(Deficiency::Protanomaly(0.5))]
+    fn test_greys_are_unchanged(#[case] deficiency: Deficiency) {
+        for level in [0, 64, 128, 192, 255] {
+            let grey = Rgb::new(level, level, level);
+            let simulated = deficiency.simulate(grey);
+
+            for (channel, expected) in [
+                (simulated.r, level),
+                (simulated.g, level),
+                (simulated.b, level),
+            ] {
+                assert!(channel.abs_diff(expected) <= 1, "{simulated:?}");
+            }
+        }
+    }
+
+    #[rstest]
+    #[case(Deficiency::Protanomaly(0.0))]
+    #[case(Deficiency::Deuteranomaly(0.0))]
+    #[case(Deficiency::Tritanomaly(-1.0))]
+    fn test_zero_severity_is_normal_vision(#[case] deficiency: Deficiency) {
+        let colour = Rgb::new(70, 130, 180);
+
+        assert_eq!(colour, deficiency.simulate(colour));
+    }
+
+    #[rstest]
+    #[case(Deficiency::Protanomaly(1.0), Deficiency::Protanopia)]
+    #[case(Deficiency::Deuteranomaly(2.0), Deficiency::Deuteranopia)]
+    #[case(Deficiency::Tritanomaly(1.0), Deficiency::Tritanopia)]
+    fn test_full_severity_is_dichromacy(
+        #[case] anomaly: Deficiency,
+        #[case] dichromacy: Deficiency,
+    ) {
+        let colour = Rgb::new(220, 20, 60);
+
+        assert_eq!(dichromacy.simulate(colour), anomaly.simulate(colour));
+    }
+
+    #[test]
+    fn test_severity_moves_towards_dichromacy() {
+        let red = Rgb::new(255, 0, 0);
+        let mild = delta_e(red, Deficiency::Protanomaly(0.3).simulate(red));
+        let severe = delta_e(red, Deficiency::Protanomaly(0.8).simulate(red));
+
+        assert!(0.0 < mild && mild < severe);
+    }
+
+    #[rstest]
+    #[case(Deficiency::Protanopia, Rgb::new(255, 0, 0))]
+    #[case(Deficiency::Deuteranopia, Rgb::new(0, 255, 0))]
+    #[case(Deficiency::Tritanopia, Rgb::new(0, 0, 255))]
+    fn test_lost_colour_changes(#[case] deficiency: Deficiency, #[case] colour: Rgb<u8>) {
+        assert!(delta_e(colour, simulate(colour, deficiency)) > 10.0);
+    }
+
+    #[test]
+    fn test_red_and_green_are_confused_with_deuteranopia() {
+        let red = Rgb::new(200, 40, 40);
+        let green = Rgb::new(90, 110, 40);
+
+        assert!(delta_e(red, green) > 30.0);
+        assert_eq!(
+            vec![(red, green)],
+            indistinguishable_pairs(&[red, green], Deficiency::Deuteranopia, 10.0)
+        );
+        assert!(indistinguishable_pairs(&[red, green], Deficiency::Tritanopia, 10.0).is_empty());
+    }
+
+    #[cfg(feature = "extended")]
+    #[test]
+    fn test_named_colours() {
+        use crate::ext::{Blue, Green, NamedColour, Red};
+
+        let palette = [
+            NamedColour::from(Red::Red),
+            NamedColour::from(Green::Green),
+            NamedColour::from(Blue::Blue),
+        ];
+
+        assert_eq!(
+            vec![(palette[0], palette[1])],
+            indistinguishable_pairs(&palette, Deficiency::Protanopia, 15.0)
+        );
+        assert_ne!(
+            Red::Red.to_rgb(),
+            simulate(Red::Red, Deficiency::Protanopia)
+        );
+    }
+}
@@ -0,0 +1,225 @@
+//! Export named colours as CSS custom properties, SCSS variables or LESS variables
+//!
+//! ## Examples
+//!
+//! ### Export a set of named colours
+//!
+//! ```
+//! use named_colour::export::{export, Case, Format};
+//! use named_colour::ext::{Blue, Red};
+//!
+//! let css = export([Blue::SteelBlue.into(), Red::Crimson.into()], Format::Css, Case::Kebab);
+//!
+//! assert_eq!(":root {\n  --steel-blue: #4682B4;\n  --crimson: #DC143C;\n}\n", css);
+//! ```
+//!
+//! ### Export a whole family
+//!
+//! ```
+//! use named_colour::export::{export_family, Case, Format};
+//! use named_colour::ext::Brown;
+//!
+//! let scss = export_family::<Brown>(Format::Scss, Case::Snake);
+//!
+//! assert!(scss.starts_with("$saddle_brown: #8B4513;\n$sienna: #A0522D;\n"));
+//! ```
+//!
+
+use std::{collections::HashSet, fmt::Write};
+
+use strum::IntoEnumIterator;
+
+use crate::ext::NamedColour;
+
+/// The stylesheet syntax to export
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Format {
+    /// CSS custom properties in a `:root` block, e.g. `--steel-blue: #4682B4;`
+    Css,
+    /// SCSS variables, e.g. `$steel-blue: #4682B4;`
+    Scss,
+    /// LESS variables, e.g. `@steel-blue: #4682B4;`
+    Less,
+}
+
+/// The case used to build variable names from colour names
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum Case {
+    /// Lowercase words separated by `-`, e.g. `steel-blue`
+    #[default]
+    Kebab,
+    /// Lowercase words separated by `_`, e.g. `steel_blue`
+    Snake,
+    /// Words joined with every word after the first capitalised, e.g. `steelBlue`
+    Camel,
+}
+
+impl Case {
+    /// Convert a colour name such as `SteelBlue` to the case
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use named_colour::export::Case;
+    ///     assert_eq!("light_slate_gray", Case::Snake.convert("LightSlateGray"));
+    /// ```
+    pub fn convert(&self, name: &str) -> String {
+        let separator = match self {
+            Self::Kebab => Some('-'),
+            Self::Snake => Some('_'),
+            Self::Camel => None,
+        };
+
+        let mut converted = String::with_capacity(name.len() + 4);
+        for (index, c) in name.chars().enumerate() {
+            if index == 0 {
+                converted.push(c.to_ascii_lowercase());
+            } else if c.is_ascii_uppercase() {
+                match separator {
+                    Some(separator) => {
+                        converted.push(separator);
+                        converted.push(c.to_ascii_lowercase());
+                    }
+                    None => converted.push(c),
+                }
+            } else {
+                converted.push(c);
+            }
+        }
+        converted
+    }
+}
+
+/// Export a set of named colours
+///
+/// Each colour is written on its own line, in the order given, as a variable
+/// named after the colour in the requested case. A name that has already been
+/// written is skipped, so colours that appear in more than one family (such as
+/// `YellowGreen`) are only written once.
+pub fn export(
+    colours: impl IntoIterator<Item = NamedColour>,
+    format: Format,
+    case: Case,
+) -> String {
+    let mut exported = String::new();
+    let mut seen = HashSet::new();
+
+    let indent = match format {
+        Format::Css => {
+            exported.push_str(":root {\n");
+            "  "
+        }
+        Format::Scss | Format::Less => "",
+    };
+    let sigil = match format {
+        Format::Css => "--",
+        Format::Scss => "$",
+        Format::Less => "@",
+    };
+
+    for colour in colours {
+        if !seen.insert(colour.name()) {
+            continue;
+        }
+        let name = case.convert(colour.name());
+        // Writing to a String cannot fail
+        let _ = writeln!(exported, "{indent}{sigil}{name}: {colour};");
+    }
+
+    if format == Format::Css {
+        exported.push_str("}\n");
+    }
+
+    exported
+}
+
+/// Export every colour in a family such as `ext::Blue`
+pub fn export_family<T>(format: Format, case: Case) -> String
+where
+    T: IntoEnumIterator + Into<NamedColour>,
+{
+    export(T::iter().map(Into::into), format, case)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ext::{Black, Blue, Cyan, Green, Yellow};
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(Case::Kebab, "SteelBlue", "steel-blue")]
+    #[case(Case::Snake, "SteelBlue", "steel_blue")]
+    #[case(Case::Camel, "SteelBlue", "steelBlue")]
+    #[case(Case::Kebab, "Tan", "tan")]
+    #[case(Case::Camel, "Tan", "tan")]
+    #[case(Case::Kebab, "LightGoldenrodYellow", "light-goldenrod-yellow")]
+    #[case(Case::Snake, "MediumAquaMarine", "medium_aqua_marine")]
+    #[case(Case::Camel, "MediumAquaMarine", "mediumAquaMarine")]
+    fn test_case_convert(#[case] case: Case, #[case] name: &str, #[case] expected: &str) {
+        assert_eq!(expected, case.convert(name));
+    }
+
+    #[rstest]
+    #[case(
+        Format::Css,
+        Case::Kebab,
+        ":root {\n  --steel-blue: #4682B4;\n  --dark-slate-gray: #2F4F4F;\n}\n"
+    )]
+    #[case(
+        Format::Scss,
+        Case::Kebab,
+        "$steel-blue: #4682B4;\n$dark-slate-gray: #2F4F4F;\n"
+    )]
+    #[case(
+        Format::Less,
+        Case::Kebab,
+        "@steel-blue: #4682B4;\n@dark-slate-gray: #2F4F4F;\n"
+    )]
+    #[case(
+        Format::Css,
+        Case::Snake,
+        ":root {\n  --steel_blue: #4682B4;\n  --dark_slate_gray: #2F4F4F;\n}\n"
+    )]
+    #[case(
+        Format::Scss,
+        Case::Camel,
+        "$steelBlue: #4682B4;\n$darkSlateGray: #2F4F4F;\n"
+    )]
+    fn test_export(#[case] format: Format, #[case] case: Case, #[case] expected: &str) {
+        let colours = [Blue::SteelBlue.into(), Cyan::DarkSlateGray.into()];
+
+        assert_eq!(expected, export(colours, format, case));
+    }
+
+    #[test]
+    fn test_export_empty() {
+        assert_eq!(":root {\n}\n", export([], Format::Css, Case::Kebab));
+        assert_eq!("", export([], Format::Less, Case::Kebab));
+    }
+
+    #[test]
+    fn test_export_skips_repeated_names() {
+        let colours = [
+            Green::YellowGreen.into(),
+            Yellow::YellowGreen.into(),
+            Black::Gray.into(),
+            Black::Grey.into(),
+        ];
+
+        assert_eq!(
+            "@yellow-green: #9ACD32;\n@gray: #808080;\n@grey: #808080;\n",
+            export(colours, Format::Less, Case::Kebab)
+        );
+    }
+
+    #[test]
+    fn test_export_family() {
+        let css = export_family::<Blue>(Format::Css, Case::Kebab);
+
+        assert!(css.starts_with(":root {\n  --powder-blue: #B0E0E6;\n"));
+        assert!(css.contains("  --steel-blue: #4682B4;\n"));
+        assert!(css.ends_with("  --light-steel-blue: #B0C4DE;\n}\n"));
+        assert_eq!(Blue::iter().count() + 2, css.lines().count());
+    }
+}
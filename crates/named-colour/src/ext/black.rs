@@ -1,136 +1,32 @@
 //! Extended named colours providing shades collected in enums for the main colour
 //!
 
-use std::fmt;
-
-use rgb::Rgb;
-use strum::EnumCount;
-use tinyrand::{RandRange, StdRand};
-
-use super::ExtendedColour;
-
-/// Shades of black
-#[derive(Debug, Clone, Copy, PartialEq, EnumCount, Eq, Hash)]
-#[allow(missing_docs)]
-pub enum Black {
-    SlateGray,
-    SlateGrey,
-    LightSlateGray,
-    LightSlateGrey,
-    Black,
-    DimGray,
-    DimGrey,
-    Gray,
-    Grey,
-    DarkGray,
-    DarkGrey,
-    Silver,
-    LightGray,
-    LightGrey,
-    Gainsboro,
-}
-
-impl fmt::Display for Black {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match self {
-            Black::SlateGray => write!(f, "#708090"),
-            Black::SlateGrey => write!(f, "#708090"),
-            Black::LightSlateGray => write!(f, "#778899"),
-            Black::LightSlateGrey => write!(f, "#778899"),
-            Black::Black => write!(f, "#000000"),
-            Black::DimGray => write!(f, "#696969"),
-            Black::DimGrey => write!(f, "#696969"),
-            Black::Gray => write!(f, "#808080"),
-            Black::Grey => write!(f, "#808080"),
-            Black::DarkGray => write!(f, "#A9A9A9"),
-            Black::DarkGrey => write!(f, "#A9A9A9"),
-            Black::Silver => write!(f, "#C0C0C0"),
-            Black::LightGray => write!(f, "#D3D3D3"),
-            Black::LightGrey => write!(f, "#D3D3D3"),
-            Black::Gainsboro => write!(f, "#DCDCDC"),
-        }
+colour_table! {
+    /// Shades of black
+    pub enum Black {
+        SlateGray = "#708090",
+        SlateGrey = "#708090" alias SlateGray,
+        LightSlateGray = "#778899",
+        LightSlateGrey = "#778899" alias LightSlateGray,
+        Black = "#000000",
+        DimGray = "#696969",
+        DimGrey = "#696969" alias DimGray,
+        Gray = "#808080",
+        Grey = "#808080" alias Gray,
+        DarkGray = "#A9A9A9",
+        DarkGrey = "#A9A9A9" alias DarkGray,
+        Silver = "#C0C0C0",
+        LightGray = "#D3D3D3",
+        LightGrey = "#D3D3D3" alias LightGray,
+        Gainsboro = "#DCDCDC",
     }
 }
 
-impl_colour_methods!(Black);
-
-impl Black {
-    /// Parse a colour from string
-    ///
-    /// ## Example
-    ///
-    ///```
-    /// # use named_colour::ext::Black;
-    /// # fn main() {
-    ///    let colour = Black::parse("gainsboro");
-    ///
-    ///    assert_eq!(Some(Black::Gainsboro), colour);
-    /// # }
-    /// ```
-    ///
-    pub fn parse(name: &str) -> Option<Self> {
-        match name.to_lowercase().as_str() {
-            "#708090" | "708090" | "slategray" => Some(Self::SlateGray),
-            "slategrey" => Some(Self::SlateGrey),
-            "#778899" | "778899" | "lightslategray" => Some(Self::LightSlateGray),
-            "lightslategrey" => Some(Self::LightSlateGrey),
-            "#000000" | "000000" | "black" => Some(Self::Black),
-            "#696969" | "696969" | "dimgray" => Some(Self::DimGray),
-            "dimgrey" => Some(Self::DimGrey),
-            "#808080" | "808080" | "gray" => Some(Self::Gray),
-            "grey" => Some(Self::Grey),
-            "#a9a9a9" | "a9a9a9" | "darkgray" => Some(Self::DarkGray),
-            "darkgrey" => Some(Self::DarkGrey),
-            "#c0c0c0" | "c0c0c0" | "silver" => Some(Self::Silver),
-            "#d3d3d3" | "d3d3d3" | "lightgray" => Some(Self::LightGray),
-            "lightgrey" => Some(Self::LightGrey),
-            "#dcdcdc" | "dcdcdc" | "gainsboro" => Some(Self::Gainsboro),
-            _ => None,
-        }
-    }
-
-    /// Generate a random colour
-    ///     
-    /// ## Example
-    ///
-    ///```
-    /// # use named_colour::ext::Black;
-    /// # fn main() {
-    ///    let colour = Black::random();
-    ///
-    /// # }
-    /// ```
-    pub fn random() -> Self {
-        let mut rand = StdRand::default();
-
-        match rand.next_range(0..Self::COUNT) {
-            0 => Black::SlateGray,
-            1 => Black::SlateGrey,
-            2 => Black::LightSlateGray,
-            3 => Black::LightSlateGrey,
-            4 => Black::Black,
-            5 => Black::DimGray,
-            6 => Black::DimGrey,
-            7 => Black::Gray,
-            8 => Black::Grey,
-            9 => Black::DarkGray,
-            10 => Black::DarkGrey,
-            11 => Black::Silver,
-            12 => Black::LightGray,
-            13 => Black::LightGrey,
-            14 => Black::Gainsboro,
-            _ => Black::Black,
-        }
-    }
-}
-
-impl ExtendedColour for Black {}
-
 #[cfg(test)]
 mod tests {
     use std::str::FromStr;
 
-    use crate::Prefix;
+    use crate::{ExtendedColour, Prefix};
 
     use super::*;
     use rstest::rstest;
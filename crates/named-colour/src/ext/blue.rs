@@ -1,143 +1,34 @@
 //! Extended named colours providing shades collected in enums for the main colour
 //!
 
-use std::fmt;
-
-use rgb::Rgb;
-use strum::EnumCount;
-use tinyrand::{RandRange, StdRand};
-
-use super::ExtendedColour;
-
-/// Shades of blue
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, EnumCount)]
-#[allow(missing_docs)]
-pub enum Blue {
-    PowderBlue,
-    CadetBlue,
-    SteelBlue,
-    CornflowerBlue,
-    DeepSkyBlue,
-    DodgerBlue,
-    LightBlue,
-    SkyBlue,
-    LightSkyBlue,
-    MidnightBlue,
-    Navy,
-    DarkBlue,
-    MediumBlue,
-    Blue,
-    RoyalBlue,
-    Azure,
-    LightSteelBlue,
-}
-
-impl fmt::Display for Blue {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match self {
-            Self::PowderBlue => write!(f, "#B0E0E6"),
-            Self::CadetBlue => write!(f, "#5F9EA0"),
-            Self::SteelBlue => write!(f, "#4682B4"),
-            Self::CornflowerBlue => write!(f, "#6495ED"),
-            Self::DeepSkyBlue => write!(f, "#00BFFF"),
-            Self::DodgerBlue => write!(f, "#1E90FF"),
-            Self::LightBlue => write!(f, "#ADD8E6"),
-            Self::SkyBlue => write!(f, "#87CEEB"),
-            Self::LightSkyBlue => write!(f, "#87CEFA"),
-            Self::LightSteelBlue => write!(f, "#B0C4DE"),
-            Self::MidnightBlue => write!(f, "#191970"),
-            Self::Navy => write!(f, "#000080"),
-            Self::DarkBlue => write!(f, "#00008B"),
-            Self::MediumBlue => write!(f, "#0000CD"),
-            Self::Blue => write!(f, "#0000FF"),
-            Self::RoyalBlue => write!(f, "#4169E1"),
-            Self::Azure => write!(f, "#F0FFFF"),
-        }
+colour_table! {
+    /// Shades of blue
+    pub enum Blue {
+        PowderBlue = "#B0E0E6",
+        CadetBlue = "#5F9EA0",
+        SteelBlue = "#4682B4",
+        CornflowerBlue = "#6495ED",
+        DeepSkyBlue = "#00BFFF",
+        DodgerBlue = "#1E90FF",
+        LightBlue = "#ADD8E6",
+        SkyBlue = "#87CEEB",
+        LightSkyBlue = "#87CEFA",
+        MidnightBlue = "#191970",
+        Navy = "#000080",
+        DarkBlue = "#00008B",
+        MediumBlue = "#0000CD",
+        Blue = "#0000FF",
+        RoyalBlue = "#4169E1",
+        Azure = "#F0FFFF",
+        LightSteelBlue = "#B0C4DE",
     }
 }
 
-impl_colour_methods!(Blue);
-
-impl Blue {
-    /// Parse a colour from string
-    ///
-    /// ## Example
-    ///
-    ///```
-    /// # use named_colour::ext::Blue;
-    /// # use named_colour::Prefix;
-    ///    let colour = Blue::Azure;
-    ///
-    ///     assert_eq!(Blue::Azure, Blue::parse("#F0FFFF").unwrap());
-    ///
-    ///```
-    pub fn parse(name: &str) -> Option<Self> {
-        match name.to_lowercase().as_str() {
-            "#b0e0e6" | "b0e0e6" | "powderblue" => Some(Self::PowderBlue),
-            "#5f9ea0" | "5f9ea0" | "cadetblue" => Some(Self::CadetBlue),
-            "#4682b4" | "4682b4" | "steelblue" => Some(Self::SteelBlue),
-            "#6495ed" | "6495ed" | "cornflowerblue" => Some(Self::CornflowerBlue),
-            "#00bfff" | "00bfff" | "deepskyblue" => Some(Self::DeepSkyBlue),
-            "#1e90ff" | "1e90ff" | "dodgerblue" => Some(Self::DodgerBlue),
-            "#add8e6" | "add8e6" | "lightblue" => Some(Self::LightBlue),
-            "#87ceeb" | "87ceeb" | "skyblue" => Some(Self::SkyBlue),
-            "#b0c4de" | "b0c4de" | "lightsteelblue" => Some(Self::LightSteelBlue),
-            "#87cefa" | "87cefa" | "lightskyblue" => Some(Self::LightSkyBlue),
-            "#191970" | "191970" | "midnightblue" => Some(Self::MidnightBlue),
-            "#000080" | "000080" | "navy" => Some(Self::Navy),
-            "#00008b" | "00008b" | "darkblue" => Some(Self::DarkBlue),
-            "#0000cd" | "0000cd" | "mediumblue" => Some(Self::MediumBlue),
-            "#0000ff" | "0000ff" | "blue" => Some(Self::Blue),
-            "#4169e1" | "4169e1" | "royalblue" => Some(Self::RoyalBlue),
-            "#f0ffff" | "f0ffff" | "azure" => Some(Self::Azure),
-            _ => None,
-        }
-    }
-
-    /// Generate a random colour
-    ///     
-    /// ## Example
-    ///
-    ///```
-    /// # use named_colour::ext::Blue;
-    /// # fn main() {
-    ///    let colour = Blue::random();
-    ///
-    /// # }
-    /// ```
-    pub fn random() -> Self {
-        let mut rand = StdRand::default();
-
-        match rand.next_range(0..Self::COUNT) {
-            0 => Self::PowderBlue,
-            1 => Self::CadetBlue,
-            2 => Self::SteelBlue,
-            3 => Self::CornflowerBlue,
-            4 => Self::DeepSkyBlue,
-            5 => Self::DodgerBlue,
-            6 => Self::LightBlue,
-            7 => Self::SkyBlue,
-            8 => Self::LightSkyBlue,
-            9 => Self::LightSteelBlue,
-            10 => Self::MidnightBlue,
-            11 => Self::Navy,
-            12 => Self::DarkBlue,
-            13 => Self::MediumBlue,
-            14 => Self::Blue,
-            15 => Self::RoyalBlue,
-            16 => Self::Azure,
-            _ => Self::Blue,
-        }
-    }
-}
-
-impl ExtendedColour for Blue {}
-
 #[cfg(test)]
 mod tests {
     use std::str::FromStr;
 
-    use crate::Prefix;
+    use crate::{ExtendedColour, Prefix};
 
     use super::*;
     use rstest::rstest;
@@ -1,105 +1,25 @@
 //! Extended named colours providing shades collected in enums for the main colour
 //!
 
-use std::fmt;
-
-use rgb::Rgb;
-use strum::EnumCount;
-use tinyrand::{RandRange, StdRand};
-
-use super::ExtendedColour;
-
-/// Shades of brown
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, EnumCount)]
-#[allow(missing_docs)]
-pub enum Brown {
-    SaddleBrown,
-    Sienna,
-    Chocolate,
-    Peru,
-    SandyBrown,
-    BurlyWood,
-    Tan,
-    RosyBrown,
-}
-
-impl fmt::Display for Brown {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match self {
-            Self::SaddleBrown => write!(f, "#8B4513"),
-            Self::Sienna => write!(f, "#A0522D"),
-            Self::Chocolate => write!(f, "#D2691E"),
-            Self::Peru => write!(f, "#CD853F"),
-            Self::SandyBrown => write!(f, "#F4A460"),
-            Self::BurlyWood => write!(f, "#DEB887"),
-            Self::Tan => write!(f, "#D2B48C"),
-            Self::RosyBrown => write!(f, "#BC8F8F"),
-        }
+colour_table! {
+    /// Shades of brown
+    pub enum Brown {
+        SaddleBrown = "#8B4513",
+        Sienna = "#A0522D",
+        Chocolate = "#D2691E",
+        Peru = "#CD853F",
+        SandyBrown = "#F4A460",
+        BurlyWood = "#DEB887",
+        Tan = "#D2B48C",
+        RosyBrown = "#BC8F8F",
     }
 }
 
-impl_colour_methods!(Brown);
-
-impl Brown {
-    /// Parse a colour from string
-    ///
-    /// ## Example
-    ///
-    /// ```
-    /// # use named_colour::ext::Brown;
-    ///     let colour = Brown::SandyBrown;
-    ///     assert_eq!(Some(Brown::SandyBrown), Brown::parse("sandybrown"));    
-    /// ```    
-    ///
-    pub fn parse(name: &str) -> Option<Self> {
-        match name.to_lowercase().as_str() {
-            "#8b4513" | "8b4513" | "saddlebrown" => Some(Self::SaddleBrown),
-            "#a0522d" | "a0522d" | "sienna" => Some(Self::Sienna),
-            "#d2691e" | "d2691e" | "chocolate" => Some(Self::Chocolate),
-            "#cd853f" | "cd853f" | "peru" => Some(Self::Peru),
-            "#f4a460" | "f4a460" | "sandybrown" => Some(Self::SandyBrown),
-            "#deb887" | "deb887" | "burlywood" => Some(Self::BurlyWood),
-            "#d2b48c" | "d2b48c" | "tan" => Some(Self::Tan),
-            "#bc8f8f" | "bc8f8f" | "rosybrown" => Some(Self::RosyBrown),
-            _ => None,
-        }
-    }
-
-    /// Generate a random colour
-    ///     
-    /// ## Example
-    ///
-    ///```
-    /// # use named_colour::ext::Brown;
-    /// # fn main() {
-    ///    let colour = Brown::random();
-    ///
-    /// # }
-    /// ```
-    pub fn random() -> Self {
-        let mut rand = StdRand::default();
-
-        match rand.next_range(0..Self::COUNT) {
-            0 => Self::SaddleBrown,
-            1 => Self::Sienna,
-            2 => Self::Chocolate,
-            3 => Self::Peru,
-            4 => Self::SandyBrown,
-            5 => Self::BurlyWood,
-            6 => Self::Tan,
-            7 => Self::RosyBrown,
-            _ => Self::Peru,
-        }
-    }
-}
-
-impl ExtendedColour for Brown {}
-
 #[cfg(test)]
 mod tests {
     use std::str::FromStr;
 
-    use crate::Prefix;
+    use crate::{ExtendedColour, Prefix};
 
     use super::*;
     use rstest::rstest;
@@ -1,135 +1,32 @@
 //! Extended named colours providing shades collected in enums for the main colour
 //!
 
-use std::fmt;
-
-use rgb::Rgb;
-use strum::EnumCount;
-use tinyrand::{RandRange, StdRand};
-
-use super::ExtendedColour;
-
-/// Shades of cyan
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, EnumCount)]
-#[allow(missing_docs)]
-pub enum Cyan {
-    MediumAquaMarine,
-    MediumSeaGreen,
-    LightSeaGreen,
-    DarkSlateGray,
-    Teal,
-    DarkCyan,
-    Aqua,
-    Cyan,
-    LightCyan,
-    DarkTurquoise,
-    Turquoise,
-    MediumTurquoise,
-    PaleTurquoise,
-    AquaMarine,
-    Honeydew,
-}
-
-impl fmt::Display for Cyan {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match self {
-            Self::MediumAquaMarine => write!(f, "#66CDAA"),
-            Self::MediumSeaGreen => write!(f, "#3CB371"),
-            Self::LightSeaGreen => write!(f, "#20B2AA"),
-            Self::DarkSlateGray => write!(f, "#2F4F4F"),
-            Self::Teal => write!(f, "#008080"),
-            Self::DarkCyan => write!(f, "#008B8B"),
-            Self::Aqua => write!(f, "#00FFFF"),
-            Self::Cyan => write!(f, "#00FFFF"),
-            Self::LightCyan => write!(f, "#E0FFFF"),
-            Self::DarkTurquoise => write!(f, "#00CED1"),
-            Self::Turquoise => write!(f, "#40E0D0"),
-            Self::MediumTurquoise => write!(f, "#48D1CC"),
-            Self::PaleTurquoise => write!(f, "#AFEEEE"),
-            Self::AquaMarine => write!(f, "#7FFFD4"),
-            Self::Honeydew => write!(f, "#F0FFF0"),
-        }
+colour_table! {
+    /// Shades of cyan
+    pub enum Cyan {
+        MediumAquaMarine = "#66CDAA",
+        MediumSeaGreen = "#3CB371",
+        LightSeaGreen = "#20B2AA",
+        DarkSlateGray = "#2F4F4F",
+        Teal = "#008080",
+        DarkCyan = "#008B8B",
+        Aqua = "#00FFFF",
+        Cyan = "#00FFFF" alias Aqua,
+        LightCyan = "#E0FFFF",
+        DarkTurquoise = "#00CED1",
+        Turquoise = "#40E0D0",
+        MediumTurquoise = "#48D1CC",
+        PaleTurquoise = "#AFEEEE",
+        AquaMarine = "#7FFFD4",
+        Honeydew = "#F0FFF0",
     }
 }
 
-impl_colour_methods!(Cyan);
-
-impl Cyan {
-    /// Parse a colour from string
-    ///
-    /// ## Example
-    ///
-    ///```
-    /// # use named_colour::ext::Cyan;
-    /// # use named_colour::Prefix;
-    ///    let colour = Cyan::Teal;
-    ///
-    ///     assert_eq!(Some(Cyan::Teal), Cyan::parse("teal"));
-    ///
-    ///```
-    pub fn parse(name: &str) -> Option<Self> {
-        match name.to_lowercase().as_str() {
-            "#66cdaa" | "66cdaa" | "mediumaquamarine" => Some(Self::MediumAquaMarine),
-            "#3cb371" | "3cb371" | "mediumseagreen" => Some(Self::MediumSeaGreen),
-            "#20b2aa" | "20b2aa" | "lightseagreen" => Some(Self::LightSeaGreen),
-            "#2f4f4f" | "2f4f4f" | "darkslategray" => Some(Self::DarkSlateGray),
-            "#008080" | "008080" | "teal" => Some(Self::Teal),
-            "#008b8b" | "008b8b" | "darkcyan" => Some(Self::DarkCyan),
-            "#00ffff" | "00ffff" | "aqua" => Some(Self::Aqua),
-            "cyan" => Some(Self::Cyan),
-            "#e0ffff" | "e0ffff" | "lightcyan" => Some(Self::LightCyan),
-            "#00ced1" | "00ced1" | "darkturquoise" => Some(Self::DarkTurquoise),
-            "#40e0d0" | "40e0d0" | "turquoise" => Some(Self::Turquoise),
-            "#48d1cc" | "48d1cc" | "mediumturquoise" => Some(Self::MediumTurquoise),
-            "#afeeee" | "afeeee" | "paleturquoise" => Some(Self::PaleTurquoise),
-            "#7fffd4" | "7fffd4" | "aquamarine" => Some(Self::AquaMarine),
-            "#f0fff0" | "f0fff0" | "honeydew" => Some(Self::Honeydew),
-            _ => None,
-        }
-    }
-
-    /// Generate a random colour
-    ///     
-    /// ## Example
-    ///
-    ///```
-    /// # use named_colour::ext::Cyan;
-    /// # fn main() {
-    ///    let colour = Cyan::random();
-    ///
-    /// # }
-    /// ```
-    pub fn random() -> Self {
-        let mut rand = StdRand::default();
-
-        match rand.next_range(0..Self::COUNT) {
-            0 => Self::MediumAquaMarine,
-            1 => Self::MediumSeaGreen,
-            2 => Self::LightSeaGreen,
-            3 => Self::DarkSlateGray,
-            4 => Self::Teal,
-            5 => Self::DarkCyan,
-            6 => Self::Aqua,
-            7 => Self::Cyan,
-            8 => Self::LightCyan,
-            9 => Self::DarkTurquoise,
-            10 => Self::Turquoise,
-            11 => Self::MediumTurquoise,
-            12 => Self::PaleTurquoise,
-            13 => Self::AquaMarine,
-            14 => Self::Honeydew,
-            _ => Self::Cyan,
-        }
-    }
-}
-
-impl ExtendedColour for Cyan {}
-
 #[cfg(test)]
 mod tests {
     use std::str::FromStr;
 
-    use crate::Prefix;
+    use crate::{ExtendedColour, Prefix};
 
     use super::*;
     use rstest::rstest;
@@ -0,0 +1,222 @@
+//! The families the extended colours are collected in
+//!
+
+use std::fmt;
+
+use rgb::Rgb;
+
+use super::{all_named_colours, NamedColour};
+use crate::oklab::Oklch;
+
+/// A family of extended colours, one for each colour enum
+///
+/// ## Example
+///
+/// ```
+/// # use named_colour::ext::{Blue, Family, NamedColour};
+///     let blues: Vec<NamedColour> = Family::Blue.members().collect();
+///
+///     assert!(blues.contains(&NamedColour::Blue(Blue::SteelBlue)));
+///     assert_eq!(Blue::ALL.len(), blues.len());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Family {
+    /// Shades of black, see [`Black`](super::Black)
+    Black,
+    /// Shades of blue, see [`Blue`](super::Blue)
+    Blue,
+    /// Shades of brown, see [`Brown`](super::Brown)
+    Brown,
+    /// Shades of cyan, see [`Cyan`](super::Cyan)
+    Cyan,
+    /// Shades of green, see [`Green`](super::Green)
+    Green,
+    /// Shades of purple, see [`Purple`](super::Purple)
+    Purple,
+    /// Shades of red, see [`Red`](super::Red)
+    Red,
+    /// Shades of white, see [`White`](super::White)
+    White,
+    /// Shades of yellow, see [`Yellow`](super::Yellow)
+    Yellow,
+}
+
+impl Family {
+    /// Every family in order
+    pub const ALL: &'static [Family] = &[
+        Self::Black,
+        Self::Blue,
+        Self::Brown,
+        Self::Cyan,
+        Self::Green,
+        Self::Purple,
+        Self::Red,
+        Self::White,
+        Self::Yellow,
+    ];
+
+    /// Iterate over every family in order
+    pub fn iter() -> impl Iterator<Item = Family> {
+        Self::ALL.iter().copied()
+    }
+
+    /// Return the name of the family
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Black => "Black",
+            Self::Blue => "Blue",
+            Self::Brown => "Brown",
+            Self::Cyan => "Cyan",
+            Self::Green => "Green",
+            Self::Purple => "Purple",
+            Self::Red => "Red",
+            Self::White => "White",
+            Self::Yellow => "Yellow",
+        }
+    }
+
+    /// Iterate over the colours of the family in declaration order
+    pub fn members(&self) -> impl Iterator<Item = NamedColour> {
+        let family = *self;
+        all_named_colours().filter(move |colour| colour.family() == family)
+    }
+}
+
+/// Place any colour in the family it most resembles
+///
+/// The colour is classified by its OKLCH lightness, chroma and hue:
+///
+/// - colours with almost no chroma are Black, or White if very light;
+/// - very light, pale oranges and yellows, such as cream and wheat, are White;
+/// - oranges are Red if vivid, and Brown if dark or muted;
+/// - dark yellows, such as olive, are Green;
+/// - pinks and magentas are Purple;
+/// - every other colour goes by its hue.
+///
+/// Most named colours classify into their own family. The exceptions are the
+/// very pale LightCyan, Honeydew, Azure and Lavender, and the pale yellows
+/// PeachPuff, Moccasin and PapayaWhip, which classify as White; the yellow
+/// family's YellowGreen and the cyan family's MediumSeaGreen, which classify
+/// as Green.
+///
+/// ## Example
+///
+/// ```
+/// # use named_colour::ext::{classify_family, Family};
+/// # use rgb::Rgb;
+///     assert_eq!(Family::Brown, classify_family(Rgb::new(150, 80, 20)));
+///     assert_eq!(Family::Red, classify_family(Rgb::new(250, 130, 20)));
+///     assert_eq!(Family::Black, classify_family(Rgb::new(90, 92, 95)));
+/// ```
+pub fn classify_family(colour: impl Into<Rgb<u8>>) -> Family {
+    let Oklch { l, c, h } = Oklch::from(colour.into());
+
+    if c < 0.035 {
+        return if l < 0.93 {
+            Family::Black
+        } else {
+            Family::White
+        };
+    }
+    if l >= 0.9 && c < 0.08 && (55.0..100.0).contains(&h) {
+        return Family::White;
+    }
+
+    match h {
+        h if h < 15.0 => Family::Purple,
+        h if h < 44.0 && c < 0.1 => Family::Brown,
+        h if h < 44.0 => Family::Red,
+        h if h < 78.0 && c < 0.16 => Family::Brown,
+        h if h < 78.0 => Family::Red,
+        h if h < 120.0 && l < 0.6 => Family::Green,
+        h if h < 120.0 => Family::Yellow,
+        h if h < 160.0 => Family::Green,
+        h if h < 198.0 => Family::Cyan,
+        h if h < 280.0 => Family::Blue,
+        _ => Family::Purple,
+    }
+}
+
+impl fmt::Display for Family {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ext::{Black, Green, Yellow};
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(Family::Black, Black::ALL.len())]
+    #[case(Family::Green, Green::ALL.len())]
+    #[case(Family::Yellow, Yellow::ALL.len())]
+    fn test_members(#[case] family: Family, #[case] expected: usize) {
+        assert_eq!(expected, family.members().count());
+        assert!(family.members().all(|colour| colour.family() == family));
+    }
+
+    #[test]
+    fn test_families_cover_every_colour() {
+        let members: usize = Family::iter().map(|family| family.members().count()).sum();
+
+        assert_eq!(all_named_colours().count(), members);
+    }
+
+    #[test]
+    fn test_yellow_green_is_in_both_families() {
+        assert!(Family::Green
+            .members()
+            .any(|colour| colour == NamedColour::Green(Green::YellowGreen)));
+        assert!(Family::Yellow
+            .members()
+            .any(|colour| colour == NamedColour::Yellow(Yellow::YellowGreen)));
+    }
+
+    #[test]
+    fn test_classify_named_colours() {
+        use crate::ext::{Blue, Cyan, Purple, White};
+
+        let exceptions = [
+            (NamedColour::Cyan(Cyan::LightCyan), Family::White),
+            (NamedColour::Cyan(Cyan::Honeydew), Family::White),
+            (NamedColour::Blue(Blue::Azure), Family::White),
+            (NamedColour::Purple(Purple::Lavender), Family::White),
+            (NamedColour::Yellow(Yellow::PeachPuff), Family::White),
+            (NamedColour::Yellow(Yellow::Moccasin), Family::White),
+            (NamedColour::Yellow(Yellow::PapayaWhip), Family::White),
+            (NamedColour::Cyan(Cyan::MediumSeaGreen), Family::Green),
+        ];
+
+        for colour in all_named_colours() {
+            let expected = exceptions
+                .iter()
+                .find(|(exception, _)| *exception == colour.canonical())
+                .map_or(colour.canonical().family(), |(_, family)| *family);
+
+            assert_eq!(expected, classify_family(colour), "{}", colour.name());
+        }
+        assert_eq!(Family::White, classify_family(White::White));
+    }
+
+    #[rstest]
+    #[case(Rgb::new(0, 0, 0), Family::Black)]
+    #[case(Rgb::new(255, 255, 255), Family::White)]
+    #[case(Rgb::new(120, 60, 10), Family::Brown)]
+    #[case(Rgb::new(255, 120, 0), Family::Red)]
+    #[case(Rgb::new(100, 100, 20), Family::Green)]
+    #[case(Rgb::new(240, 220, 60), Family::Yellow)]
+    #[case(Rgb::new(20, 170, 150), Family::Cyan)]
+    #[case(Rgb::new(30, 60, 200), Family::Blue)]
+    #[case(Rgb::new(230, 90, 150), Family::Purple)]
+    fn test_classify_family(#[case] colour: Rgb<u8>, #[case] expected: Family) {
+        assert_eq!(expected, classify_family(colour));
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!("Purple", Family::Purple.to_string());
+    }
+}
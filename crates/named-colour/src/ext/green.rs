@@ -1,147 +1,35 @@
 //! Extended named colours providing shades collected in enums for the main colour
 //!
 
-use std::fmt;
-
-use rgb::Rgb;
-use strum::EnumCount;
-use tinyrand::{RandRange, StdRand};
-
-use super::ExtendedColour;
-
-/// Shades of green
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, EnumCount)]
-#[allow(missing_docs)]
-pub enum Green {
-    YellowGreen,
-    DarkOliveGreen,
-    Olive,
-    OliveDrab,
-    LawnGreen,
-    ChartReuse,
-    GreenYellow,
-    DarkGreen,
-    Green,
-    ForestGreen,
-    Lime,
-    LimeGreen,
-    LightGreen,
-    PaleGreen,
-    DarkSeaGreen,
-    MediumSpringGreen,
-    SpringGreen,
-    SeaGreen,
-}
-
-impl fmt::Display for Green {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match self {
-            Self::YellowGreen => write!(f, "#9ACD32"),
-            Self::DarkOliveGreen => write!(f, "#556B2F"),
-            Self::Olive => write!(f, "#808000"),
-            Self::OliveDrab => write!(f, "#6B8E23"),
-            Self::LawnGreen => write!(f, "#7CFC00"),
-            Self::ChartReuse => write!(f, "#7FFF00"),
-            Self::GreenYellow => write!(f, "#ADFF2F"),
-            Self::DarkGreen => write!(f, "#006400"),
-            Self::Green => write!(f, "#008000"),
-            Self::ForestGreen => write!(f, "#228B22"),
-            Self::Lime => write!(f, "#00FF00"),
-            Self::LimeGreen => write!(f, "#32CD32"),
-            Self::LightGreen => write!(f, "#90EE90"),
-            Self::PaleGreen => write!(f, "#98FB98"),
-            Self::DarkSeaGreen => write!(f, "#8FBC8F"),
-            Self::MediumSpringGreen => write!(f, "#00FA9A"),
-            Self::SpringGreen => write!(f, "#00FF7F"),
-            Self::SeaGreen => write!(f, "#2E8B57"),
-        }
+colour_table! {
+    /// Shades of green
+    pub enum Green {
+        YellowGreen = "#9ACD32",
+        DarkOliveGreen = "#556B2F",
+        Olive = "#808000",
+        OliveDrab = "#6B8E23",
+        LawnGreen = "#7CFC00",
+        ChartReuse = "#7FFF00",
+        GreenYellow = "#ADFF2F",
+        DarkGreen = "#006400",
+        Green = "#008000",
+        ForestGreen = "#228B22",
+        Lime = "#00FF00",
+        LimeGreen = "#32CD32",
+        LightGreen = "#90EE90",
+        PaleGreen = "#98FB98",
+        DarkSeaGreen = "#8FBC8F",
+        MediumSpringGreen = "#00FA9A",
+        SpringGreen = "#00FF7F",
+        SeaGreen = "#2E8B57",
     }
 }
 
-impl_colour_methods!(Green);
-
-impl Green {
-    /// Parse a colour from string
-    ///
-    /// ## Example
-    ///
-    ///```
-    /// # use named_colour::ext::Green;
-    /// # fn main() {
-    ///    let colour = Green::parse("#7CFC00");
-    ///    assert_eq!(Some(Green::LawnGreen), colour);
-    ///
-    ///  # }
-    ///```
-    pub fn parse(name: &str) -> Option<Self> {
-        match name.to_lowercase().as_str() {
-            "#9acd32" | "9acd32" | "yellowgreen" => Some(Self::YellowGreen),
-            "#556b2f" | "556b2f" | "darkolivegreen" => Some(Self::DarkOliveGreen),
-            "#808000" | "808000" | "olive" => Some(Self::Olive),
-            "#6b8e23" | "6b8e23" | "olivedrab" => Some(Self::OliveDrab),
-            "#7cfc00" | "7cfc00" | "lawngreen" => Some(Self::LawnGreen),
-            "#7fff00" | "7fff00" | "chartreuse" => Some(Self::ChartReuse),
-            "#adff2f" | "adff2f" | "greenyellow" => Some(Self::GreenYellow),
-            "#006400" | "006400" | "darkgreen" => Some(Self::DarkGreen),
-            "#008000" | "008000" | "green" => Some(Self::Green),
-            "#228b22" | "228b22" | "forestgreen" => Some(Self::ForestGreen),
-            "#32cd32" | "32cd32" | "limegreen" => Some(Self::LimeGreen),
-            "#90ee90" | "90ee90" | "lightgreen" => Some(Self::LightGreen),
-            "#98fb98" | "98fb98" | "palegreen" => Some(Self::PaleGreen),
-            "#8fbc8f" | "8fbc8f" | "darkseagreen" => Some(Self::DarkSeaGreen),
-            "#00fa9a" | "00fa9a" | "mediumspringgreen" => Some(Self::MediumSpringGreen),
-            "#00ff7f" | "00ff7f" | "springgreen" => Some(Self::SpringGreen),
-            "#2e8b57" | "2e8b57" | "seagreen" => Some(Self::SeaGreen),
-            "#00ff00" | "00ff00" | "lime" => Some(Self::Lime),
-            _ => None,
-        }
-    }
-
-    /// Generate a random colour
-    ///     
-    /// ## Example
-    ///
-    ///```
-    /// # use named_colour::ext::Green;
-    /// # fn main() {
-    ///    let colour = Green::random();
-    ///
-    /// # }
-    /// ```
-    pub fn random() -> Self {
-        let mut rand = StdRand::default();
-
-        match rand.next_range(0..Self::COUNT) {
-            0 => Self::YellowGreen,
-            1 => Self::DarkOliveGreen,
-            2 => Self::Olive,
-            3 => Self::OliveDrab,
-            4 => Self::LawnGreen,
-            5 => Self::ChartReuse,
-            6 => Self::GreenYellow,
-            7 => Self::DarkGreen,
-            8 => Self::Green,
-            9 => Self::ForestGreen,
-            10 => Self::Lime,
-            11 => Self::LimeGreen,
-            12 => Self::LightGreen,
-            13 => Self::PaleGreen,
-            14 => Self::DarkSeaGreen,
-            15 => Self::MediumSpringGreen,
-            16 => Self::SpringGreen,
-            17 => Self::SeaGreen,
-            _ => Self::Green,
-        }
-    }
-}
-
-impl ExtendedColour for Green {}
-
 #[cfg(test)]
 mod tests {
     use std::str::FromStr;
 
-    use crate::Prefix;
+    use crate::{ExtendedColour, Prefix};
 
     use super::*;
     use rstest::rstest;
@@ -1,28 +1,30 @@
 //! Extended named colours providing shades collected in enums for the main colour
 //!
 
-#[macro_use]
-mod macros;
-
-mod black;
-mod blue;
-mod brown;
-mod cyan;
-mod green;
-mod purple;
-mod red;
-mod white;
-mod yellow;
+pub(crate) mod black;
+pub(crate) mod blue;
+pub(crate) mod brown;
+pub(crate) mod cyan;
+mod family;
+pub(crate) mod green;
+mod named;
+pub(crate) mod purple;
+pub(crate) mod red;
+pub(crate) mod white;
+pub(crate) mod yellow;
 
 use std::str::FromStr;
 
-use tinyrand::{RandRange, StdRand};
+use crate::random::{from_entropy, Rand};
 
 pub use black::Black;
 pub use blue::Blue;
 pub use brown::Brown;
 pub use cyan::Cyan;
+pub use family::{classify_family, Family};
 pub use green::Green;
+pub(crate) use named::distance;
+pub use named::{all_named_colours, lookup, lookup_all, NamedColour};
 pub use purple::Purple;
 pub use red::Red;
 pub use white::White;
@@ -76,6 +78,9 @@ pub fn name_colour<T: ExtendedColour + FromStr>(colour: &str) -> Option<T> {
 
 /// Returns a random colour
 ///
+/// Every colour in the extended set is equally likely, see
+/// [`NamedColour::random`].
+///
 /// # Examples
 ///
 /// ```rust
@@ -86,20 +91,30 @@ pub fn name_colour<T: ExtendedColour + FromStr>(colour: &str) -> Option<T> {
 /// ```
 ///
 pub fn random_named_colour() -> Box<dyn ExtendedColour> {
-    let mut rand = StdRand::default();
+    random_named_colour_with(&mut from_entropy())
+}
 
-    let family = rand.next_range(0..8_u32);
-    match family {
-        0 => Box::new(Black::random()),
-        1 => Box::new(Blue::random()),
-        2 => Box::new(Brown::random()),
-        3 => Box::new(Cyan::random()),
-        4 => Box::new(Green::random()),
-        5 => Box::new(Purple::random()),
-        6 => Box::new(Red::random()),
-        7 => Box::new(White::random()),
-        8 => Box::new(Yellow::random()),
-        _ => Box::new(Black::random()),
+/// Returns a random colour chosen with the given generator
+///
+/// # Examples
+///
+/// ```rust
+/// # use named_colour::random::{Seeded, StdRand};
+///     let colour = named_colour::random_named_colour_with(&mut StdRand::seed(7));
+///
+/// ```
+///
+pub fn random_named_colour_with<R: Rand>(rand: &mut R) -> Box<dyn ExtendedColour> {
+    match NamedColour::random_with(rand) {
+        NamedColour::Black(colour) => Box::new(colour),
+        NamedColour::Blue(colour) => Box::new(colour),
+        NamedColour::Brown(colour) => Box::new(colour),
+        NamedColour::Cyan(colour) => Box::new(colour),
+        NamedColour::Green(colour) => Box::new(colour),
+        NamedColour::Purple(colour) => Box::new(colour),
+        NamedColour::Red(colour) => Box::new(colour),
+        NamedColour::White(colour) => Box::new(colour),
+        NamedColour::Yellow(colour) => Box::new(colour),
     }
 }
 
@@ -123,6 +138,55 @@ mod tests {
 
     use super::*;
 
+    #[rstest]
+    #[case(Black::TABLE)]
+    #[case(Blue::TABLE)]
+    #[case(Brown::TABLE)]
+    #[case(Cyan::TABLE)]
+    #[case(Green::TABLE)]
+    #[case(Purple::TABLE)]
+    #[case(Red::TABLE)]
+    #[case(White::TABLE)]
+    #[case(Yellow::TABLE)]
+    fn test_table_round_trip<T>(#[case] table: &[crate::table::Row<T>])
+    where
+        T: Copy + PartialEq + std::fmt::Debug + std::fmt::Display + FromStr + Into<rgb::Rgb<u8>>,
+        T::Err: std::fmt::Debug,
+    {
+        crate::table::assert_round_trip(table);
+    }
+
+    #[rstest]
+    #[case(Black::ALL, Black::TABLE)]
+    #[case(Blue::ALL, Blue::TABLE)]
+    #[case(Brown::ALL, Brown::TABLE)]
+    #[case(Cyan::ALL, Cyan::TABLE)]
+    #[case(Green::ALL, Green::TABLE)]
+    #[case(Purple::ALL, Purple::TABLE)]
+    #[case(Red::ALL, Red::TABLE)]
+    #[case(White::ALL, White::TABLE)]
+    #[case(Yellow::ALL, Yellow::TABLE)]
+    fn test_all_in_table_order<T>(#[case] all: &[T], #[case] table: &[crate::table::Row<T>])
+    where
+        T: Copy + PartialEq,
+    {
+        assert!(all.iter().copied().eq(table.iter().map(|row| row.colour)));
+    }
+
+    #[rstest]
+    #[case(Yellow::Goldenrod, "#DAA520")]
+    #[case(Purple::Fuchsia, "#FF00FF")]
+    fn test_display_parses_to_same_colour<T>(#[case] colour: T, #[case] expected: &str)
+    where
+        T: Copy + PartialEq + std::fmt::Debug + std::fmt::Display + FromStr + Into<rgb::Rgb<u8>>,
+        T::Err: std::fmt::Debug,
+    {
+        let parsed = T::from_str(expected).unwrap();
+
+        assert_eq!(expected, colour.to_string());
+        assert_eq!(colour.into(), parsed.into());
+    }
+
     #[rstest]
     #[case("#123456", Some(AnonColour::NotNamed))]
     #[case("#708090", Some(Black::SlateGray))]
@@ -387,8 +451,8 @@ mod tests {
     #[case("#9932cc", Some(Purple::DarkOrchid))]
     #[case("9932cc", Some(Purple::DarkOrchid))]
     #[case("darkorchid", Some(Purple::DarkOrchid))]
-    #[case("#a020f0", Some(Purple::Fuchsia))]
-    #[case("a020f0", Some(Purple::Fuchsia))]
+    #[case("#a020f0", None::<Purple>)]
+    #[case("a020f0", None::<Purple>)]
     #[case("fuchsia", Some(Purple::Fuchsia))]
     #[case("#ff00ff", Some(Purple::Magenta))]
     #[case("ff00ff", Some(Purple::Magenta))]
@@ -504,8 +568,8 @@ mod tests {
     #[case("#b8860b", Some(Yellow::DarkGoldenrod))]
     #[case("b8860b", Some(Yellow::DarkGoldenrod))]
     #[case("DarkGoldenRod", Some(Yellow::DarkGoldenrod))]
-    #[case("#dab500", Some(Yellow::Goldenrod))]
-    #[case("dab500", Some(Yellow::Goldenrod))]
+    #[case("#daa520", Some(Yellow::Goldenrod))]
+    #[case("daa520", Some(Yellow::Goldenrod))]
     #[case("GoldenRod", Some(Yellow::Goldenrod))]
     #[case("#eee8aa", Some(Yellow::PaleGoldenrod))]
     #[case("eee8aa", Some(Yellow::PaleGoldenrod))]
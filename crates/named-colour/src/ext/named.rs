@@ -0,0 +1,574 @@
+//! A named colour drawn from any of the extended colour families
+//!
+
+use std::fmt;
+
+use rgb::Rgb;
+use tinyrand::RandRange;
+
+use super::{Black, Blue, Brown, Cyan, Family, Green, Purple, Red, White, Yellow};
+use crate::random::{from_entropy, Rand};
+
+/// A named colour from any of the extended colour families
+///
+/// Every family enum converts into a `NamedColour` with `From`, so functions
+/// that accept any named colour can take `impl Into<NamedColour>`.
+///
+/// ## Example
+///
+/// ```
+/// # use named_colour::ext::{Blue, NamedColour};
+///     let colour = NamedColour::from(Blue::SteelBlue);
+///
+///     assert_eq!("SteelBlue", colour.name());
+///     assert_eq!("#4682B4", colour.to_string());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NamedColour {
+    /// A shade of black
+    Black(Black),
+    /// A shade of blue
+    Blue(Blue),
+    /// A shade of brown
+    Brown(Brown),
+    /// A shade of cyan
+    Cyan(Cyan),
+    /// A shade of green
+    Green(Green),
+    /// A shade of purple
+    Purple(Purple),
+    /// A shade of red
+    Red(Red),
+    /// A shade of white
+    White(White),
+    /// A shade of yellow
+    Yellow(Yellow),
+}
+
+macro_rules! impl_from_family {
+    ($($family:ident),*) => {
+        $(
+            impl From<$family> for NamedColour {
+                fn from(colour: $family) -> Self {
+                    Self::$family(colour)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_family!(Black, Blue, Brown, Cyan, Green, Purple, Red, White, Yellow);
+
+impl fmt::Display for NamedColour {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Black(colour) => colour.fmt(f),
+            Self::Blue(colour) => colour.fmt(f),
+            Self::Brown(colour) => colour.fmt(f),
+            Self::Cyan(colour) => colour.fmt(f),
+            Self::Green(colour) => colour.fmt(f),
+            Self::Purple(colour) => colour.fmt(f),
+            Self::Red(colour) => colour.fmt(f),
+            Self::White(colour) => colour.fmt(f),
+            Self::Yellow(colour) => colour.fmt(f),
+        }
+    }
+}
+
+impl From<NamedColour> for Rgb<u8> {
+    fn from(colour: NamedColour) -> Self {
+        colour.to_rgb()
+    }
+}
+
+impl NamedColour {
+    /// Display the colour as an RGB tuple
+    pub const fn to_rgb(&self) -> Rgb<u8> {
+        match self {
+            Self::Black(colour) => colour.to_rgb(),
+            Self::Blue(colour) => colour.to_rgb(),
+            Self::Brown(colour) => colour.to_rgb(),
+            Self::Cyan(colour) => colour.to_rgb(),
+            Self::Green(colour) => colour.to_rgb(),
+            Self::Purple(colour) => colour.to_rgb(),
+            Self::Red(colour) => colour.to_rgb(),
+            Self::White(colour) => colour.to_rgb(),
+            Self::Yellow(colour) => colour.to_rgb(),
+        }
+    }
+
+    /// Find a colour by name, ignoring ASCII case, in `const` context
+    ///
+    /// Families are searched in order, so `YellowGreen` is found in `Green`.
+    pub(crate) const fn from_name(name: &str) -> Option<Self> {
+        macro_rules! find_in {
+            ($($family:ident),*) => {
+                $(
+                    if let Some(colour) = $family::from_name(name) {
+                        return Some(Self::$family(colour));
+                    }
+                )*
+            };
+        }
+
+        find_in!(Black, Blue, Brown, Cyan, Green, Purple, Red, White, Yellow);
+        None
+    }
+
+    /// Return the canonical colour with the same RGB value
+    ///
+    /// Every RGB value has one canonical colour, the first colour in family
+    /// order that is not an alias within its family. This is the colour
+    /// [`lookup`](super::lookup) finds first, so `Yellow::YellowGreen` is an
+    /// alias of `Green::YellowGreen`.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use named_colour::ext::{Black, Green, NamedColour, Yellow};
+    ///     let grey = NamedColour::from(Black::Grey);
+    ///     let yellow_green = NamedColour::from(Yellow::YellowGreen);
+    ///
+    ///     assert_eq!(NamedColour::from(Black::Gray), grey.canonical());
+    ///     assert_eq!(NamedColour::from(Green::YellowGreen), yellow_green.canonical());
+    /// ```
+    pub const fn canonical(&self) -> Self {
+        let rgb = self.to_rgb();
+
+        macro_rules! first_in {
+            ($($family:ident),*) => {
+                $(
+                    let mut i = 0;
+                    while i < $family::ALL.len() {
+                        let colour = $family::ALL[i];
+                        let other = colour.to_rgb();
+                        if !colour.is_alias() && other.r == rgb.r && other.g == rgb.g && other.b == rgb.b {
+                            return Self::$family(colour);
+                        }
+                        i += 1;
+                    }
+                )*
+            };
+        }
+
+        first_in!(Black, Blue, Brown, Cyan, Green, Purple, Red, White, Yellow);
+        *self
+    }
+
+    /// Return true if the colour is not its own canonical colour
+    pub const fn is_alias(&self) -> bool {
+        !self.is(&self.canonical())
+    }
+
+    /// Return true if the colour is an alias of `other`, which is its
+    /// canonical colour
+    pub const fn is_alias_of(&self, other: &Self) -> bool {
+        self.is_alias() && self.canonical().is(other)
+    }
+
+    /// Return true if the colours have the same canonical colour, and so the
+    /// same RGB value
+    ///
+    /// This holds for aliases such as `Black::Grey` and `Black::Gray`, and for
+    /// colours in more than one family such as `YellowGreen`.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use named_colour::ext::{Black, Green, NamedColour, Yellow};
+    ///     let grey = NamedColour::from(Black::Grey);
+    ///     let yellow_green = NamedColour::from(Green::YellowGreen);
+    ///
+    ///     assert!(grey.same_colour(&NamedColour::from(Black::Gray)));
+    ///     assert!(yellow_green.same_colour(&NamedColour::from(Yellow::YellowGreen)));
+    /// ```
+    pub const fn same_colour(&self, other: &Self) -> bool {
+        self.canonical().is(&other.canonical())
+    }
+
+    /// Return true if the colours are the same variant, in `const` context
+    const fn is(&self, other: &Self) -> bool {
+        macro_rules! same_variant {
+            ($($family:ident),*) => {
+                match (self, other) {
+                    $((Self::$family(a), Self::$family(b)) => *a as usize == *b as usize,)*
+                    _ => false,
+                }
+            };
+        }
+
+        same_variant!(Black, Blue, Brown, Cyan, Green, Purple, Red, White, Yellow)
+    }
+
+    /// Iterate over the other named colours with the same canonical colour, in
+    /// family order
+    ///
+    /// These are exactly the colours [`same_colour`](Self::same_colour) holds
+    /// for, so they include colours in other families as well as aliases.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use named_colour::ext::{Green, NamedColour, Yellow};
+    ///     let yellow_green = NamedColour::from(Green::YellowGreen);
+    ///
+    ///     assert_eq!(
+    ///         vec![NamedColour::from(Yellow::YellowGreen)],
+    ///         yellow_green.aliases().collect::<Vec<_>>()
+    ///     );
+    /// ```
+    pub fn aliases(&self) -> impl Iterator<Item = NamedColour> {
+        let colour = *self;
+        all_named_colours().filter(move |other| *other != colour && other.same_colour(&colour))
+    }
+
+    /// Return the family of the colour
+    pub fn family(&self) -> Family {
+        match self {
+            Self::Black(_) => Family::Black,
+            Self::Blue(_) => Family::Blue,
+            Self::Brown(_) => Family::Brown,
+            Self::Cyan(_) => Family::Cyan,
+            Self::Green(_) => Family::Green,
+            Self::Purple(_) => Family::Purple,
+            Self::Red(_) => Family::Red,
+            Self::White(_) => Family::White,
+            Self::Yellow(_) => Family::Yellow,
+        }
+    }
+
+    /// Return the name of the colour
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Black(colour) => colour.name(),
+            Self::Blue(colour) => colour.name(),
+            Self::Brown(colour) => colour.name(),
+            Self::Cyan(colour) => colour.name(),
+            Self::Green(colour) => colour.name(),
+            Self::Purple(colour) => colour.name(),
+            Self::Red(colour) => colour.name(),
+            Self::White(colour) => colour.name(),
+            Self::Yellow(colour) => colour.name(),
+        }
+    }
+
+    /// Generate a random colour from the whole extended set
+    ///
+    /// Every colour is equally likely, using a generator seeded from entropy.
+    /// A colour in several families, such as `YellowGreen`, is counted once
+    /// for each family.
+    pub fn random() -> Self {
+        Self::random_with(&mut from_entropy())
+    }
+
+    /// Generate a random colour from the whole extended set with the given
+    /// generator
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use named_colour::ext::NamedColour;
+    /// # use named_colour::random::{Seeded, StdRand};
+    ///     let colour = NamedColour::random_with(&mut StdRand::seed(7));
+    ///
+    ///     assert_eq!(colour, NamedColour::random_with(&mut StdRand::seed(7)));
+    /// ```
+    pub fn random_with<R: Rand>(rand: &mut R) -> Self {
+        all_named_colours()
+            .nth(rand.next_range(0..COUNT))
+            .expect("the index is less than the number of colours")
+    }
+
+    /// Find the named colour nearest to an RGB colour
+    ///
+    /// Distance is measured as the squared euclidean distance between the red,
+    /// green and blue components. Aliases are never returned and where several
+    /// colours are equally near, the first in family order is returned.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use named_colour::ext::{Red, NamedColour};
+    /// # use rgb::Rgb;
+    ///     let colour = NamedColour::nearest(Rgb::new(250, 5, 5));
+    ///
+    ///     assert_eq!(NamedColour::Red(Red::Red), colour);
+    /// ```
+    pub fn nearest(colour: Rgb<u8>) -> Self {
+        all_named_colours()
+            .filter(|named| !named.is_alias())
+            .min_by_key(|named| distance(colour, named.to_rgb()))
+            .expect("the extended colour set is not empty")
+    }
+}
+
+/// The number of colours in the extended set, counting each family
+const COUNT: usize = Black::ALL.len()
+    + Blue::ALL.len()
+    + Brown::ALL.len()
+    + Cyan::ALL.len()
+    + Green::ALL.len()
+    + Purple::ALL.len()
+    + Red::ALL.len()
+    + White::ALL.len()
+    + Yellow::ALL.len();
+
+#[cfg(feature = "rand")]
+impl rand::distr::Distribution<NamedColour> for rand::distr::StandardUniform {
+    fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> NamedColour {
+        all_named_colours()
+            .nth(rng.random_range(0..COUNT))
+            .expect("the index is less than the number of colours")
+    }
+}
+
+/// Iterate over every colour in the extended set in family order
+///
+/// Colours in more than one family, such as `YellowGreen`, appear once for
+/// each family.
+///
+/// ## Example
+///
+/// ```
+/// # use named_colour::ext::{all_named_colours, Purple, NamedColour};
+///     let colours: Vec<NamedColour> = all_named_colours().collect();
+///
+///     assert!(colours.contains(&NamedColour::Purple(Purple::DarkOrchid)));
+/// ```
+pub fn all_named_colours() -> impl Iterator<Item = NamedColour> {
+    Black::iter()
+        .map(NamedColour::from)
+        .chain(Blue::iter().map(NamedColour::from))
+        .chain(Brown::iter().map(NamedColour::from))
+        .chain(Cyan::iter().map(NamedColour::from))
+        .chain(Green::iter().map(NamedColour::from))
+        .chain(Purple::iter().map(NamedColour::from))
+        .chain(Red::iter().map(NamedColour::from))
+        .chain(White::iter().map(NamedColour::from))
+        .chain(Yellow::iter().map(NamedColour::from))
+}
+
+/// Find every colour matching a hex code or name, in any family
+///
+/// Input is matched as by each family's `parse()`: names and hex codes ignoring
+/// case, with an optional `#` on hex codes. A hex code matches the canonical
+/// colour of each family holding it, never an alias, while a name matches the
+/// colour of that name.
+///
+/// Matches are returned in family order, `Black` to `Yellow`, so a colour in
+/// several families such as `YellowGreen` is found in `Green` before `Yellow`.
+///
+/// ## Example
+///
+/// ```
+/// # use named_colour::ext::{lookup_all, Green, NamedColour, Yellow};
+///     let colours = lookup_all("yellowgreen");
+///
+///     assert_eq!(
+///         vec![
+///             NamedColour::Green(Green::YellowGreen),
+///             NamedColour::Yellow(Yellow::YellowGreen)
+///         ],
+///         colours
+///     );
+/// ```
+pub fn lookup_all(input: &str) -> Vec<NamedColour> {
+    let mut colours = Vec::new();
+
+    macro_rules! lookup_in {
+        ($($family:ident),*) => {
+            $(
+                if let Some(colour) = $family::parse(input) {
+                    colours.push(NamedColour::from(colour));
+                }
+            )*
+        };
+    }
+
+    lookup_in!(Black, Blue, Brown, Cyan, Green, Purple, Red, White, Yellow);
+    colours
+}
+
+/// Find the first colour matching a hex code or name, in any family
+///
+/// Returns the first match of [`lookup_all`] in family order.
+///
+/// ## Example
+///
+/// ```
+/// # use named_colour::ext::{lookup, Blue, Green, NamedColour};
+///     assert_eq!(Some(NamedColour::Blue(Blue::SteelBlue)), lookup("#4682b4"));
+///     assert_eq!(Some(NamedColour::Green(Green::YellowGreen)), lookup("YellowGreen"));
+///     assert_eq!(None, lookup("not a colour"));
+/// ```
+pub fn lookup(input: &str) -> Option<NamedColour> {
+    macro_rules! lookup_in {
+        ($($family:ident),*) => {
+            $(
+                if let Some(colour) = $family::parse(input) {
+                    return Some(NamedColour::from(colour));
+                }
+            )*
+        };
+    }
+
+    lookup_in!(Black, Blue, Brown, Cyan, Green, Purple, Red, White, Yellow);
+    None
+}
+
+/// The squared euclidean distance between two RGB colours
+pub(crate) fn distance(a: Rgb<u8>, b: Rgb<u8>) -> u32 {
+    let dr = a.r.abs_diff(b.r) as u32;
+    let dg = a.g.abs_diff(b.g) as u32;
+    let db = a.b.abs_diff(b.b) as u32;
+
+    dr * dr + dg * dg + db * db
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(Rgb::new(0, 0, 0), NamedColour::Black(Black::Black))]
+    #[case(Rgb::new(112, 128, 144), NamedColour::Black(Black::SlateGray))]
+    #[case(Rgb::new(0, 255, 255), NamedColour::Cyan(Cyan::Aqua))]
+    #[case(Rgb::new(70, 130, 180), NamedColour::Blue(Blue::SteelBlue))]
+    #[case(Rgb::new(72, 128, 182), NamedColour::Blue(Blue::SteelBlue))]
+    #[case(Rgb::new(154, 205, 50), NamedColour::Green(Green::YellowGreen))]
+    #[case(Rgb::new(254, 254, 254), NamedColour::White(White::White))]
+    fn test_nearest(#[case] colour: Rgb<u8>, #[case] expected: NamedColour) {
+        assert_eq!(expected, NamedColour::nearest(colour));
+    }
+
+    #[rstest]
+    #[case("darkorchid", Some(NamedColour::Purple(Purple::DarkOrchid)))]
+    #[case("LightGrey", Some(NamedColour::Black(Black::LightGrey)))]
+    #[case("YELLOWGREEN", Some(NamedColour::Green(Green::YellowGreen)))]
+    #[case("#9932CC", None)]
+    #[case("dark orchid", None)]
+    fn test_from_name(#[case] name: &str, #[case] expected: Option<NamedColour>) {
+        assert_eq!(expected, NamedColour::from_name(name));
+    }
+
+    #[test]
+    fn test_every_colour_is_its_own_nearest_rgb() {
+        for colour in all_named_colours() {
+            assert_eq!(
+                colour.to_rgb(),
+                NamedColour::nearest(colour.to_rgb()).to_rgb()
+            );
+        }
+    }
+
+    #[rstest]
+    #[case(NamedColour::from(Purple::DarkOrchid), "DarkOrchid", "#9932CC")]
+    #[case(NamedColour::from(Black::LightGrey), "LightGrey", "#D3D3D3")]
+    #[case(NamedColour::from(Yellow::LightYellow), "LightYellow", "#FFFFE0")]
+    fn test_name_and_display(#[case] colour: NamedColour, #[case] name: &str, #[case] hex: &str) {
+        assert_eq!(name, colour.name());
+        assert_eq!(hex, colour.to_string());
+    }
+
+    #[rstest]
+    #[case(NamedColour::from(Black::Gray), Family::Black)]
+    #[case(NamedColour::from(Green::YellowGreen), Family::Green)]
+    #[case(NamedColour::from(Yellow::YellowGreen), Family::Yellow)]
+    fn test_family(#[case] colour: NamedColour, #[case] expected: Family) {
+        assert_eq!(expected, colour.family());
+    }
+
+    #[rstest]
+    #[case(NamedColour::from(Black::Grey), NamedColour::from(Black::Gray))]
+    #[case(NamedColour::from(Black::Gray), NamedColour::from(Black::Gray))]
+    #[case(NamedColour::from(Cyan::Cyan), NamedColour::from(Cyan::Aqua))]
+    #[case(NamedColour::from(Purple::Fuchsia), NamedColour::from(Purple::Magenta))]
+    #[case(
+        NamedColour::from(Yellow::YellowGreen),
+        NamedColour::from(Green::YellowGreen)
+    )]
+    #[case(
+        NamedColour::from(Green::YellowGreen),
+        NamedColour::from(Green::YellowGreen)
+    )]
+    fn test_canonical(#[case] colour: NamedColour, #[case] expected: NamedColour) {
+        assert_eq!(expected, colour.canonical());
+        assert_eq!(colour != expected, colour.is_alias_of(&expected));
+        assert!(colour.same_colour(&expected));
+    }
+
+    #[test]
+    fn test_aliases() {
+        let grey = NamedColour::from(Black::Grey);
+
+        assert_eq!(
+            vec![NamedColour::from(Black::Gray)],
+            grey.aliases().collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec![NamedColour::from(Yellow::YellowGreen)],
+            NamedColour::from(Green::YellowGreen)
+                .aliases()
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_aliases_agree_with_same_colour() {
+        for colour in all_named_colours() {
+            let aliases: Vec<_> = colour.aliases().collect();
+            for other in all_named_colours().filter(|other| *other != colour) {
+                assert_eq!(
+                    colour.same_colour(&other),
+                    aliases.contains(&other),
+                    "{colour:?} {other:?}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_aliases_share_the_canonical_colour() {
+        for colour in all_named_colours() {
+            assert!(colour
+                .aliases()
+                .all(|alias| alias.canonical() == colour.canonical()));
+            assert_eq!(colour.is_alias(), colour.is_alias_of(&colour.canonical()));
+            assert!(!colour.canonical().is_alias());
+        }
+    }
+
+    #[test]
+    fn test_nearest_is_never_an_alias() {
+        for colour in all_named_colours() {
+            assert!(!NamedColour::nearest(colour.to_rgb()).is_alias());
+        }
+    }
+
+    #[rstest]
+    #[case("SteelBlue", vec![NamedColour::Blue(Blue::SteelBlue)])]
+    #[case("#9ACD32", vec![NamedColour::Green(Green::YellowGreen), NamedColour::Yellow(Yellow::YellowGreen)])]
+    #[case("grey", vec![NamedColour::Black(Black::Grey)])]
+    #[case("#808080", vec![NamedColour::Black(Black::Gray)])]
+    #[case("00ffff", vec![NamedColour::Cyan(Cyan::Aqua)])]
+    #[case("not a colour", vec![])]
+    fn test_lookup_all(#[case] input: &str, #[case] expected: Vec<NamedColour>) {
+        assert_eq!(expected, lookup_all(input));
+        assert_eq!(expected.first().copied(), lookup(input));
+    }
+
+    #[test]
+    fn test_random_with_reaches_every_family() {
+        use crate::random::{Seeded, StdRand};
+
+        let mut rand = StdRand::seed(1);
+        let mut families: Vec<Family> = (0..2000)
+            .map(|_| NamedColour::random_with(&mut rand).family())
+            .collect();
+        families.sort();
+        families.dedup();
+
+        assert_eq!(Family::ALL, families.as_slice());
+    }
+}
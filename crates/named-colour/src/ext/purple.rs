@@ -1,173 +1,41 @@
 //! Extended named colours providing shades collected in enums for the main colour
 //!
 
-use std::fmt;
-
-use rgb::Rgb;
-use strum::EnumCount;
-use tinyrand::{RandRange, StdRand};
-
-use super::ExtendedColour;
-
-/// Shades of purple
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, EnumCount)]
-#[allow(missing_docs)]
-pub enum Purple {
-    Indigo,
-    Purple,
-    DarkMagenta,
-    DarkViolet,
-    DarkSlateBlue,
-    BlueViolet,
-    DarkOrchid,
-    Fuchsia,
-    Magenta,
-    SlateBlue,
-    MediumSlateBlue,
-    MediumOrchid,
-    MediumPurple,
-    Orchid,
-    Violet,
-    Plum,
-    Thistle,
-    Lavender,
-    Pink,
-    MediumVioletRed,
-    PaleVioletRed,
-    DeepPink,
-    HotPink,
-    LightPink,
-}
-
-impl fmt::Display for Purple {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match self {
-            Self::Indigo => write!(f, "#4B0082"),
-            Self::Purple => write!(f, "#800080"),
-            Self::DarkMagenta => write!(f, "#8B008B"),
-            Self::DarkViolet => write!(f, "#9400D3"),
-            Self::DarkSlateBlue => write!(f, "#483D8B"),
-            Self::BlueViolet => write!(f, "#8A2BE2"),
-            Self::DarkOrchid => write!(f, "#9932CC"),
-            Self::Fuchsia => write!(f, "#FF00FF"),
-            Self::Magenta => write!(f, "#FF00FF"),
-            Self::SlateBlue => write!(f, "#6A5ACD"),
-            Self::MediumSlateBlue => write!(f, "#7B68EE"),
-            Self::MediumOrchid => write!(f, "#BA55D3"),
-            Self::MediumPurple => write!(f, "#9370DB"),
-            Self::Orchid => write!(f, "#DA70D6"),
-            Self::Violet => write!(f, "#EE82EE"),
-            Self::Plum => write!(f, "#DDA0DD"),
-            Self::Thistle => write!(f, "#D8BFD8"),
-            Self::Lavender => write!(f, "#E6E6FA"),
-            Self::Pink => write!(f, "#FFC0CB"),
-            Self::MediumVioletRed => write!(f, "#C71585"),
-            Self::PaleVioletRed => write!(f, "#DB7093"),
-            Self::DeepPink => write!(f, "#FF1493"),
-            Self::HotPink => write!(f, "#FF69B4"),
-            Self::LightPink => write!(f, "#FFB6C1"),
-        }
+colour_table! {
+    /// Shades of purple
+    pub enum Purple {
+        Indigo = "#4B0082",
+        Purple = "#800080",
+        DarkMagenta = "#8B008B",
+        DarkViolet = "#9400D3",
+        DarkSlateBlue = "#483D8B",
+        BlueViolet = "#8A2BE2",
+        DarkOrchid = "#9932CC",
+        Fuchsia = "#FF00FF" alias Magenta,
+        Magenta = "#FF00FF",
+        SlateBlue = "#6A5ACD",
+        MediumSlateBlue = "#7B68EE",
+        MediumOrchid = "#BA55D3",
+        MediumPurple = "#9370DB",
+        Orchid = "#DA70D6",
+        Violet = "#EE82EE",
+        Plum = "#DDA0DD",
+        Thistle = "#D8BFD8",
+        Lavender = "#E6E6FA",
+        Pink = "#FFC0CB",
+        MediumVioletRed = "#C71585",
+        PaleVioletRed = "#DB7093",
+        DeepPink = "#FF1493",
+        HotPink = "#FF69B4",
+        LightPink = "#FFB6C1",
     }
 }
 
-impl_colour_methods!(Purple);
-
-impl Purple {
-    /// Parse a colour from string
-    ///
-    /// ## Example
-    ///
-    /// ```
-    /// # use named_colour::ext::Purple;
-    /// # fn main() {
-    ///    let colour = Purple::Purple;
-    ///    assert_eq!(colour, Purple::parse("purple").unwrap());
-    ///    assert_eq!(colour, Purple::parse("#800080").unwrap());
-    ///    assert_eq!(colour, Purple::parse("#800080").unwrap());
-    ///
-    /// # }
-    /// ```
-    pub fn parse(name: &str) -> Option<Self> {
-        match name.to_lowercase().as_str() {
-            "#4b0082" | "4b0082" | "indigo" => Some(Self::Indigo),
-            "#800080" | "800080" | "purple" => Some(Self::Purple),
-            "#8b008b" | "8b008b" | "darkmagenta" => Some(Self::DarkMagenta),
-            "#9400d3" | "9400d3" | "darkviolet" => Some(Self::DarkViolet),
-            "#483d8b" | "483d8b" | "darkslateblue" => Some(Self::DarkSlateBlue),
-            "#8a2be2" | "8a2be2" | "blueviolet" => Some(Self::BlueViolet),
-            "#9932cc" | "9932cc" | "darkorchid" => Some(Self::DarkOrchid),
-            "#a020f0" | "a020f0" | "fuchsia" => Some(Self::Fuchsia),
-            "#ff00ff" | "ff00ff" | "magenta" => Some(Self::Magenta),
-            "#6a5acd" | "6a5acd" | "slateblue" => Some(Self::SlateBlue),
-            "#7b68ee" | "7b68ee" | "mediumslateblue" => Some(Self::MediumSlateBlue),
-            "#ba55d3" | "ba55d3" | "mediumorchid" => Some(Self::MediumOrchid),
-            "#9370db" | "9370db" | "mediumpurple" => Some(Self::MediumPurple),
-            "#da70d6" | "da70d6" | "orchid" => Some(Self::Orchid),
-            "#ee82ee" | "ee82ee" | "violet" => Some(Self::Violet),
-            "#dda0dd" | "dda0dd" | "plum" => Some(Self::Plum),
-            "#d8bfd8" | "d8bfd8" | "thistle" => Some(Self::Thistle),
-            "#e6e6fa" | "e6e6fa" | "lavender" => Some(Self::Lavender),
-            "#ffc0cb" | "ffc0cb" | "pink" => Some(Self::Pink),
-            "#c71585" | "c71585" | "mediumvioletred" => Some(Self::MediumVioletRed),
-            "#db7093" | "db7093" | "palevioletred" => Some(Self::PaleVioletRed),
-            "#ff1493" | "ff1493" | "deeppink" => Some(Self::DeepPink),
-            "#ff69b4" | "ff69b4" | "hotpink" => Some(Self::HotPink),
-            "#ffb6c1" | "ffb6c1" | "lightpink" => Some(Self::LightPink),
-            _ => None,
-        }
-    }
-
-    /// Generate a random colour
-    ///     
-    /// ## Example
-    ///
-    ///```
-    /// # use named_colour::ext::Purple;
-    /// # fn main() {
-    ///    let colour = Purple::random();
-    ///
-    /// # }
-    /// ```
-    pub fn random() -> Self {
-        let mut rand = StdRand::default();
-
-        match rand.next_range(0..Self::COUNT) {
-            0 => Self::Indigo,
-            1 => Self::Purple,
-            2 => Self::DarkMagenta,
-            3 => Self::DarkViolet,
-            4 => Self::DarkSlateBlue,
-            5 => Self::BlueViolet,
-            6 => Self::DarkOrchid,
-            7 => Self::Fuchsia,
-            8 => Self::Magenta,
-            9 => Self::SlateBlue,
-            10 => Self::MediumSlateBlue,
-            11 => Self::MediumOrchid,
-            12 => Self::MediumPurple,
-            13 => Self::Orchid,
-            14 => Self::Violet,
-            15 => Self::Plum,
-            16 => Self::Thistle,
-            17 => Self::Lavender,
-            18 => Self::Pink,
-            19 => Self::MediumVioletRed,
-            20 => Self::PaleVioletRed,
-            21 => Self::DeepPink,
-            22 => Self::HotPink,
-            23 => Self::LightPink,
-            _ => Self::Purple,
-        }
-    }
-}
-
-impl ExtendedColour for Purple {}
-
 #[cfg(test)]
 mod tests {
     use std::str::FromStr;
 
-    use crate::Prefix;
+    use crate::{ExtendedColour, Prefix};
 
     use super::*;
     use rstest::rstest;
@@ -246,8 +114,6 @@ mod tests {
     #[case("#9932cc", Purple::DarkOrchid)]
     #[case("9932cc", Purple::DarkOrchid)]
     #[case("darkorchid", Purple::DarkOrchid)]
-    #[case("#a020f0", Purple::Fuchsia)]
-    #[case("a020f0", Purple::Fuchsia)]
     #[case("fuchsia", Purple::Fuchsia)]
     #[case("#ff00ff", Purple::Magenta)]
     #[case("ff00ff", Purple::Magenta)]
@@ -323,8 +189,8 @@ mod tests {
     #[case("#9932cc", Some(Purple::DarkOrchid))]
     #[case("9932cc", Some(Purple::DarkOrchid))]
     #[case("darkorchid", Some(Purple::DarkOrchid))]
-    #[case("#a020f0", Some(Purple::Fuchsia))]
-    #[case("a020f0", Some(Purple::Fuchsia))]
+    #[case("#a020f0", None)]
+    #[case("a020f0", None)]
     #[case("fuchsia", Some(Purple::Fuchsia))]
     #[case("#ff00ff", Some(Purple::Magenta))]
     #[case("ff00ff", Some(Purple::Magenta))]
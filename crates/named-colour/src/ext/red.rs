@@ -1,145 +1,33 @@
 //! Extended named colours providing shades collected in enums for the main colour
 //!
 
-use std::fmt;
-
-use rgb::Rgb;
-use strum::EnumCount;
-use tinyrand::{RandRange, StdRand};
-
-use super::ExtendedColour;
-
-/// Shades of red
-#[allow(missing_docs)]
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, EnumCount)]
-pub enum Red {
-    Maroon,
-    #[allow(clippy::enum_variant_names)]
-    DarkRed,
-    Brown,
-    Firebrick,
-    Crimson,
-    #[allow(clippy::enum_variant_names)]
-    Red,
-    Tomato,
-    Coral,
-    #[allow(clippy::enum_variant_names)]
-    IndianRed,
-    LightCoral,
-    DarkSalmon,
-    Salmon,
-    LightSalmon,
-    #[allow(clippy::enum_variant_names)]
-    OrangeRed,
-    DarkOrange,
-    Orange,
-}
-
-impl fmt::Display for Red {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match self {
-            Self::Maroon => write!(f, "#800000"),
-            Self::DarkRed => write!(f, "#8B0000"),
-            Self::Brown => write!(f, "#A52A2A"),
-            Self::Firebrick => write!(f, "#B22222"),
-            Self::Crimson => write!(f, "#DC143C"),
-            Self::Red => write!(f, "#FF0000"),
-            Self::Tomato => write!(f, "#FF6347"),
-            Self::Coral => write!(f, "#FF7F50"),
-            Self::IndianRed => write!(f, "#CD5C5C"),
-            Self::LightCoral => write!(f, "#F08080"),
-            Self::DarkSalmon => write!(f, "#E9967A"),
-            Self::Salmon => write!(f, "#FA8072"),
-            Self::LightSalmon => write!(f, "#FFA07A"),
-            Self::OrangeRed => write!(f, "#FF4500"),
-            Self::DarkOrange => write!(f, "#FF8C00"),
-            Self::Orange => write!(f, "#FFA500"),
-        }
+colour_table! {
+    /// Shades of red
+    pub enum Red {
+        Maroon = "#800000",
+        DarkRed = "#8B0000",
+        Brown = "#A52A2A",
+        Firebrick = "#B22222",
+        Crimson = "#DC143C",
+        Red = "#FF0000",
+        Tomato = "#FF6347",
+        Coral = "#FF7F50",
+        IndianRed = "#CD5C5C",
+        LightCoral = "#F08080",
+        DarkSalmon = "#E9967A",
+        Salmon = "#FA8072",
+        LightSalmon = "#FFA07A",
+        OrangeRed = "#FF4500",
+        DarkOrange = "#FF8C00",
+        Orange = "#FFA500",
     }
 }
 
-impl_colour_methods!(Red);
-
-impl Red {
-    /// Parse a colour from string
-    ///
-    /// ## Example
-    ///
-    ///```
-    /// # use named_colour::ext::Red;
-    /// # use std::str::FromStr;
-    /// # fn main() {
-    ///    let colour = Red::from_str("Maroon");
-    ///    assert_eq!(Red::Maroon, colour.unwrap());
-    ///
-    ///  # }
-    ///```      
-    ///
-    pub fn parse(name: &str) -> Option<Self> {
-        match name.to_lowercase().as_str() {
-            "#800000" | "800000" | "maroon" => Some(Self::Maroon),
-            "#8b0000" | "8b0000" | "darkred" => Some(Self::DarkRed),
-            "#a52a2a" | "a52a2a" | "brown" => Some(Self::Brown),
-            "#b22222" | "b22222" | "firebrick" => Some(Self::Firebrick),
-            "#dc143c" | "dc143c" | "crimson" => Some(Self::Crimson),
-            "#ff0000" | "ff0000" | "red" => Some(Self::Red),
-            "#ff6347" | "ff6347" | "tomato" => Some(Self::Tomato),
-            "#ff7f50" | "ff7f50" | "coral" => Some(Self::Coral),
-            "#cd5c5c" | "cd5c5c" | "indianred" => Some(Self::IndianRed),
-            "#f08080" | "f08080" | "lightcoral" => Some(Self::LightCoral),
-            "#e9967a" | "e9967a" | "darksalmon" => Some(Self::DarkSalmon),
-            "#fa8072" | "fa8072" | "salmon" => Some(Self::Salmon),
-            "#ffa07a" | "ffa07a" | "lightsalmon" => Some(Self::LightSalmon),
-            "#ff4500" | "ff4500" | "orangered" => Some(Self::OrangeRed),
-            "#ff8c00" | "ff8c00" | "darkorange" => Some(Self::DarkOrange),
-            "#ffa500" | "ffa500" | "orange" => Some(Self::Orange),
-            _ => None,
-        }
-    }
-
-    /// Generate a random colour
-    ///     
-    /// ## Example
-    ///
-    ///```
-    /// # use named_colour::ext::Red;
-    /// # fn main() {
-    ///    let colour = Red::random();
-    ///
-    /// # }
-    /// ```
-    pub fn random() -> Self {
-        let mut rand = StdRand::default();
-
-        match rand.next_range(0..Self::COUNT) {
-            0 => Self::Maroon,
-            1 => Self::DarkRed,
-            2 => Self::Brown,
-            3 => Self::Firebrick,
-            4 => Self::Crimson,
-            5 => Self::Red,
-            6 => Self::Tomato,
-            7 => Self::Coral,
-            8 => Self::IndianRed,
-            9 => Self::LightCoral,
-            10 => Self::DarkSalmon,
-            11 => Self::Salmon,
-            12 => Self::LightSalmon,
-            13 => Self::OrangeRed,
-            14 => Self::DarkOrange,
-            15 => Self::Orange,
-            _ => Self::Red,
-        }
-    }
-}
-
-impl ExtendedColour for Red {}
-
 #[cfg(test)]
 mod tests {
     use std::str::FromStr;
 
-    use crate::Prefix;
+    use crate::{ExtendedColour, Prefix};
 
     use super::*;
     use rstest::rstest;
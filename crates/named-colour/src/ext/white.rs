@@ -1,155 +1,37 @@
 //! Extended named colours providing shades collected in enums for the main colour
 //!
 
-use std::fmt;
-
-use rgb::Rgb;
-use strum::EnumCount;
-use tinyrand::{RandRange, StdRand};
-
-use super::ExtendedColour;
-
-/// Shades of white
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, EnumCount)]
-#[allow(missing_docs)]
-pub enum White {
-    AntiqueWhite,
-    Beige,
-    Bisque,
-    BlanchedAlmond,
-    Wheat,
-    CornSilk,
-    White,
-    NavajoWhite,
-    MistyRose,
-    LavenderBlush,
-    Linen,
-    OldLace,
-    SeaShell,
-    MintCream,
-    FloralWhite,
-    GhostWhite,
-    Ivory,
-    Snow,
-    WhiteSmoke,
-    AliceBlue,
-}
-
-impl fmt::Display for White {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match self {
-            Self::AntiqueWhite => write!(f, "#FAEBD7"),
-            Self::Beige => write!(f, "#F5F5DC"),
-            Self::Bisque => write!(f, "#FFE4C4"),
-            Self::BlanchedAlmond => write!(f, "#FFEBCD"),
-            Self::Wheat => write!(f, "#F5DEB3"),
-            Self::CornSilk => write!(f, "#FFF8DC"),
-            Self::White => write!(f, "#FFFFFF"),
-            Self::NavajoWhite => write!(f, "#FFDEAD"),
-            Self::MistyRose => write!(f, "#FFE4E1"),
-            Self::LavenderBlush => write!(f, "#FFF0F5"),
-            Self::Linen => write!(f, "#FAF0E6"),
-            Self::OldLace => write!(f, "#FDF5E6"),
-            Self::SeaShell => write!(f, "#FFF5EE"),
-            Self::MintCream => write!(f, "#F5FFFA"),
-            Self::FloralWhite => write!(f, "#FFFAF0"),
-            Self::GhostWhite => write!(f, "#F8F8FF"),
-            Self::Ivory => write!(f, "#FFFFF0"),
-            Self::Snow => write!(f, "#FFFAFA"),
-            Self::WhiteSmoke => write!(f, "#F5F5F5"),
-            Self::AliceBlue => write!(f, "#F0F8FF"),
-        }
+colour_table! {
+    /// Shades of white
+    pub enum White {
+        AntiqueWhite = "#FAEBD7",
+        Beige = "#F5F5DC",
+        Bisque = "#FFE4C4",
+        BlanchedAlmond = "#FFEBCD",
+        Wheat = "#F5DEB3",
+        CornSilk = "#FFF8DC",
+        White = "#FFFFFF",
+        NavajoWhite = "#FFDEAD",
+        MistyRose = "#FFE4E1",
+        LavenderBlush = "#FFF0F5",
+        Linen = "#FAF0E6",
+        OldLace = "#FDF5E6",
+        SeaShell = "#FFF5EE",
+        MintCream = "#F5FFFA",
+        FloralWhite = "#FFFAF0",
+        GhostWhite = "#F8F8FF",
+        Ivory = "#FFFFF0",
+        Snow = "#FFFAFA",
+        WhiteSmoke = "#F5F5F5",
+        AliceBlue = "#F0F8FF",
     }
 }
 
-impl_colour_methods!(White);
-
-impl White {
-    /// Parse a colour from string
-    ///
-    /// ## Example
-    ///
-    ///```
-    /// # use named_colour::ext::White;
-    /// # fn main() {
-    ///    let colour = White::parse("AntiqueWhite");
-    ///    assert_eq!(Some(White::AntiqueWhite), colour);
-    ///
-    ///  # }
-    ///```
-    pub fn parse(name: &str) -> Option<Self> {
-        match name.to_lowercase().as_str() {
-            "#faebd7" | "faebd7" | "antiquewhite" => Some(Self::AntiqueWhite),
-            "#f5f5dc" | "f5f5dc" | "beige" => Some(Self::Beige),
-            "#ffe4c4" | "ffe4c4" | "bisque" => Some(Self::Bisque),
-            "#ffebcd" | "ffebcd" | "blanchedalmond" => Some(Self::BlanchedAlmond),
-            "#f5deb3" | "f5deb3" | "wheat" => Some(Self::Wheat),
-            "#fff8dc" | "fff8dc" | "cornsilk" => Some(Self::CornSilk),
-            "#ffffff" | "ffffff" | "white" => Some(Self::White),
-            "#ffdead" | "ffdead" | "navajowhite" => Some(Self::NavajoWhite),
-            "#ffe4e1" | "ffe4e1" | "mistyrose" => Some(Self::MistyRose),
-            "#fff0f5" | "fff0f5" | "lavenderblush" => Some(Self::LavenderBlush),
-            "#faf0e6" | "faf0e6" | "linen" => Some(Self::Linen),
-            "#fdf5e6" | "fdf5e6" | "oldlace" => Some(Self::OldLace),
-            "#fff5ee" | "fff5ee" | "seashell" => Some(Self::SeaShell),
-            "#f5fffa" | "f5fffa" | "mintcream" => Some(Self::MintCream),
-            "#fffaf0" | "fffaf0" | "floralwhite" => Some(Self::FloralWhite),
-            "#f8f8ff" | "f8f8ff" | "ghostwhite" => Some(Self::GhostWhite),
-            "#fffff0" | "fffff0" | "ivory" => Some(Self::Ivory),
-            "#fffafa" | "fffafa" | "snow" => Some(Self::Snow),
-            "#f5f5f5" | "f5f5f5" | "whitesmoke" => Some(Self::WhiteSmoke),
-            "#f0f8ff" | "f0f8ff" | "aliceblue" => Some(Self::AliceBlue),
-            _ => None,
-        }
-    }
-
-    /// Generate a random colour
-    ///     
-    /// ## Example
-    ///
-    ///```
-    /// # use named_colour::ext::White;
-    /// # fn main() {
-    ///    let colour = White::random();
-    ///
-    /// # }
-    /// ```
-    pub fn random() -> Self {
-        let mut rand = StdRand::default();
-
-        match rand.next_range(0..Self::COUNT) {
-            0 => Self::AntiqueWhite,
-            1 => Self::Beige,
-            2 => Self::Bisque,
-            3 => Self::BlanchedAlmond,
-            4 => Self::Wheat,
-            5 => Self::CornSilk,
-            6 => Self::White,
-            7 => Self::NavajoWhite,
-            8 => Self::MistyRose,
-            9 => Self::LavenderBlush,
-            10 => Self::Linen,
-            11 => Self::OldLace,
-            12 => Self::SeaShell,
-            13 => Self::MintCream,
-            14 => Self::FloralWhite,
-            15 => Self::GhostWhite,
-            16 => Self::Ivory,
-            17 => Self::Snow,
-            18 => Self::WhiteSmoke,
-            19 => Self::AliceBlue,
-            _ => Self::White,
-        }
-    }
-}
-
-impl ExtendedColour for White {}
-
 #[cfg(test)]
 mod tests {
     use std::str::FromStr;
 
-    use crate::Prefix;
+    use crate::{ExtendedColour, Prefix};
 
     use super::*;
     use rstest::rstest;
@@ -1,131 +1,31 @@
 //! Extended named colours providing shades collected in enums for the main colour
 //!
 
-use std::fmt;
-
-use rgb::Rgb;
-use strum::EnumCount;
-use tinyrand::{RandRange, StdRand};
-
-use super::ExtendedColour;
-
-/// Shades of yellow
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, EnumCount)]
-#[allow(missing_docs)]
-pub enum Yellow {
-    Gold,
-    DarkGoldenrod,
-    Goldenrod,
-    PaleGoldenrod,
-    DarkKhaki,
-    Khaki,
-    Yellow,
-    YellowGreen,
-    PeachPuff,
-    Moccasin,
-    PapayaWhip,
-    LightGoldenrodYellow,
-    LemonChiffon,
-    LightYellow,
-}
-
-impl fmt::Display for Yellow {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match self {
-            Self::Gold => write!(f, "#FFD700"),
-            Self::DarkGoldenrod => write!(f, "#B8860B"),
-            Self::Goldenrod => write!(f, "#DAA520"),
-            Self::PaleGoldenrod => write!(f, "#EEE8AA"),
-            Self::PeachPuff => write!(f, "#FFDAB9"),
-            Self::Moccasin => write!(f, "#FFE4B5"),
-            Self::PapayaWhip => write!(f, "#FFEFD5"),
-            Self::DarkKhaki => write!(f, "#BDB76B"),
-            Self::LemonChiffon => write!(f, "#FFFACD"),
-            Self::LightGoldenrodYellow => write!(f, "#FAFAD2"),
-            Self::Khaki => write!(f, "#F0E68C"),
-            Self::Yellow => write!(f, "#FFFF00"),
-            Self::YellowGreen => write!(f, "#9ACD32"),
-            Self::LightYellow => write!(f, "#FFFFE0"),
-        }
+colour_table! {
+    /// Shades of yellow
+    pub enum Yellow {
+        Gold = "#FFD700",
+        DarkGoldenrod = "#B8860B",
+        Goldenrod = "#DAA520",
+        PaleGoldenrod = "#EEE8AA",
+        DarkKhaki = "#BDB76B",
+        Khaki = "#F0E68C",
+        Yellow = "#FFFF00",
+        YellowGreen = "#9ACD32",
+        PeachPuff = "#FFDAB9",
+        Moccasin = "#FFE4B5",
+        PapayaWhip = "#FFEFD5",
+        LightGoldenrodYellow = "#FAFAD2",
+        LemonChiffon = "#FFFACD",
+        LightYellow = "#FFFFE0",
     }
 }
 
-impl_colour_methods!(Yellow);
-
-impl Yellow {
-    /// Parse a colour from string
-    ///     
-    /// ## Example
-    ///
-    ///```
-    /// # use named_colour::ext::Yellow;
-    /// # use named_colour::Prefix;
-    ///     let colour = Yellow::Khaki;
-    ///     assert_eq!(Some(colour), Yellow::parse("Khaki"));
-    ///
-    ///```
-    ///
-    pub fn parse(name: &str) -> Option<Self> {
-        match name.to_lowercase().as_str() {
-            "#ffd700" | "ffd700" | "gold" => Some(Self::Gold),
-            "#b8860b" | "b8860b" | "darkgoldenrod" => Some(Self::DarkGoldenrod),
-            "#dab500" | "dab500" | "goldenrod" => Some(Self::Goldenrod),
-            "#eee8aa" | "eee8aa" | "palegoldenrod" => Some(Self::PaleGoldenrod),
-            "#bdb76b" | "bdb76b" | "darkkhaki" => Some(Self::DarkKhaki),
-            "#f0e68c" | "f0e68c" | "khaki" => Some(Self::Khaki),
-            "#ffff00" | "ffff00" | "yellow" => Some(Self::Yellow),
-            "#9acd32" | "9acd32" | "yellowgreen" => Some(Self::YellowGreen),
-            "#ffdab9" | "ffdab9" | "peachpuff" => Some(Self::PeachPuff),
-            "#ffe4b5" | "ffe4b5" | "moccasin" => Some(Self::Moccasin),
-            "#ffefd5" | "ffefd5" | "papayawhip" => Some(Self::PapayaWhip),
-            "#fffacd" | "fffacd" | "lemonchiffon" => Some(Self::LemonChiffon),
-            "#fafad2" | "fafad2" | "lightgoldenrodyellow" => Some(Self::LightGoldenrodYellow),
-            "#ffffe0" | "ffffe0" | "lightyellow" => Some(Self::LightYellow),
-            _ => None,
-        }
-    }
-
-    /// Generate a random colour
-    ///     
-    /// ## Example
-    ///
-    ///```
-    /// # use named_colour::ext::Yellow;
-    /// # fn main() {
-    ///    let colour = Yellow::random();
-    ///
-    /// # }
-    /// ```
-    pub fn random() -> Self {
-        let mut rand = StdRand::default();
-
-        match rand.next_range(0..Self::COUNT) {
-            0 => Self::Gold,
-            1 => Self::DarkGoldenrod,
-            2 => Self::Goldenrod,
-            3 => Self::PaleGoldenrod,
-            4 => Self::PeachPuff,
-            5 => Self::Moccasin,
-            6 => Self::PapayaWhip,
-            7 => Self::DarkKhaki,
-            8 => Self::LemonChiffon,
-            9 => Self::LightGoldenrodYellow,
-            10 => Self::Khaki,
-            11 => Self::Yellow,
-            12 => Self::YellowGreen,
-            13 => Self::LightYellow,
-            _ => Self::Yellow,
-        }
-    }
-}
-
-impl ExtendedColour for Yellow {}
-
 #[cfg(test)]
 mod tests {
     use std::str::FromStr;
 
-    use crate::Prefix;
+    use crate::{ExtendedColour, Prefix};
 
     use super::*;
     use rstest::rstest;
@@ -191,8 +91,8 @@ mod tests {
     #[case("#b8860b", Yellow::DarkGoldenrod)]
     #[case("b8860b", Yellow::DarkGoldenrod)]
     #[case("DarkGoldenRod", Yellow::DarkGoldenrod)]
-    #[case("#dab500", Yellow::Goldenrod)]
-    #[case("dab500", Yellow::Goldenrod)]
+    #[case("#daa520", Yellow::Goldenrod)]
+    #[case("daa520", Yellow::Goldenrod)]
     #[case("GoldenRod", Yellow::Goldenrod)]
     #[case("#eee8aa", Yellow::PaleGoldenrod)]
     #[case("eee8aa", Yellow::PaleGoldenrod)]
@@ -238,8 +138,8 @@ mod tests {
     #[case("#b8860b", Some(Yellow::DarkGoldenrod))]
     #[case("b8860b", Some(Yellow::DarkGoldenrod))]
     #[case("DarkGoldenRod", Some(Yellow::DarkGoldenrod))]
-    #[case("#dab500", Some(Yellow::Goldenrod))]
-    #[case("dab500", Some(Yellow::Goldenrod))]
+    #[case("#daa520", Some(Yellow::Goldenrod))]
+    #[case("daa520", Some(Yellow::Goldenrod))]
     #[case("GoldenRod", Some(Yellow::Goldenrod))]
     #[case("#eee8aa", Some(Yellow::PaleGoldenrod))]
     #[case("eee8aa", Some(Yellow::PaleGoldenrod))]
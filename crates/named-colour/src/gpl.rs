@@ -0,0 +1,233 @@
+//! Read and write GIMP palette (`.gpl`) files
+//!
+//! The GIMP palette format is a plain text format used by GIMP, Inkscape and
+//! Krita. A file starts with a `GIMP Palette` header, followed by optional
+//! `Name:` and `Columns:` lines, `#` comments and one colour per line given as
+//! red, green and blue values followed by the colour name.
+//!
+//! ## Examples
+//!
+//! ### Export a family
+//!
+//! ```
+//! use named_colour::ext::Brown;
+//! use named_colour::gpl;
+//!
+//! let gpl = gpl::family_to_gpl::<Brown>("Browns");
+//!
+//! assert!(gpl.starts_with("GIMP Palette\nName: Browns\nColumns: 0\n#\n139  69  19\tSaddleBrown\n"));
+//! ```
+//!
+//! ### Import a palette
+//!
+//! ```
+//! use named_colour::gpl;
+//! use rgb::Rgb;
+//!
+//! let palette = gpl::from_gpl("GIMP Palette\nName: Brand\n#\n 18  52  86\tPrimary\n").unwrap();
+//!
+//! assert_eq!(Some("Brand"), palette.name());
+//! assert_eq!(Some(Rgb::new(18, 52, 86)), palette.get("primary"));
+//! ```
+//!
+
+use std::fmt::Write;
+
+use rgb::Rgb;
+use strum::IntoEnumIterator;
+
+use crate::{ext::NamedColour, palette::Palette, ToHex};
+
+const HEADER: &str = "GIMP Palette";
+
+/// Write a set of named colours as a GIMP palette
+pub fn to_gpl(name: &str, colours: impl IntoIterator<Item = NamedColour>) -> String {
+    write_gpl(
+        name,
+        colours
+            .into_iter()
+            .map(|colour| (colour.name(), colour.to_rgb())),
+    )
+}
+
+/// Write every colour in a family such as `ext::Blue` as a GIMP palette
+pub fn family_to_gpl<T>(name: &str) -> String
+where
+    T: IntoEnumIterator + Into<NamedColour>,
+{
+    to_gpl(name, T::iter().map(Into::into))
+}
+
+/// Write a runtime palette as a GIMP palette
+///
+/// A palette without a name is written with the name `Untitled`.
+pub fn palette_to_gpl(palette: &Palette) -> String {
+    write_gpl(
+        palette.name().unwrap_or("Untitled"),
+        palette.iter().map(|entry| (entry.name(), entry.colour())),
+    )
+}
+
+fn write_gpl<'a>(name: &str, colours: impl Iterator<Item = (&'a str, Rgb<u8>)>) -> String {
+    let mut gpl = format!("{HEADER}\nName: {name}\nColumns: 0\n#\n");
+    for (name, colour) in colours {
+        // Writing to a String cannot fail
+        let _ = writeln!(gpl, "{:3} {:3} {:3}\t{name}", colour.r, colour.g, colour.b);
+    }
+    gpl
+}
+
+/// Read a GIMP palette into a runtime palette
+///
+/// Colours without a name are named by their hex code, e.g. `#4682B4`.
+/// Every colour line becomes an entry, even where a name is repeated.
+///
+/// ## Errors
+///
+/// Returns an error if the header is missing or a colour line does not start
+/// with three values between 0 and 255.
+pub fn from_gpl(gpl: &str) -> Result<Palette, String> {
+    let mut lines = gpl.lines();
+
+    match lines.next() {
+        Some(header) if header.trim_start_matches('\u{feff}').trim() == HEADER => {}
+        _ => return Err("Invalid GIMP palette: missing header".to_string()),
+    }
+
+    let mut palette = Palette::new();
+    for line in lines {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') || line.starts_with("Columns:") {
+            continue;
+        }
+        if let Some(name) = line.strip_prefix("Name:") {
+            palette.set_name(name.trim());
+            continue;
+        }
+
+        let (colour, name) = parse_colour_line(line)?;
+        match name {
+            "" => palette.push(&colour.as_hex(), colour),
+            name => palette.push(name, colour),
+        }
+    }
+
+    Ok(palette)
+}
+
+fn parse_colour_line(line: &str) -> Result<(Rgb<u8>, &str), String> {
+    let mut rest = line;
+    let mut values = [0_u8; 3];
+    for value in values.iter_mut() {
+        let end = rest.find(char::is_whitespace).unwrap_or(rest.len());
+        *value = rest[..end]
+            .parse()
+            .map_err(|_| format!("Invalid GIMP palette colour: {line}"))?;
+        rest = rest[end..].trim_start();
+    }
+
+    Ok((Rgb::new(values[0], values[1], values[2]), rest))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ext::{Black, Blue, Red};
+    use crate::palette::PaletteEntry;
+    use rstest::rstest;
+
+    #[test]
+    fn test_to_gpl() {
+        let gpl = to_gpl(
+            "Selection",
+            [
+                Blue::SteelBlue.into(),
+                Red::Crimson.into(),
+                Black::Black.into(),
+            ],
+        );
+
+        assert_eq!(
+            concat!(
+                "GIMP Palette\nName: Selection\nColumns: 0\n#\n",
+                " 70 130 180\tSteelBlue\n",
+                "220  20  60\tCrimson\n",
+                "  0   0   0\tBlack\n",
+            ),
+            gpl
+        );
+    }
+
+    #[test]
+    fn test_family_round_trip() {
+        let palette = from_gpl(&family_to_gpl::<Blue>("Blues")).unwrap();
+
+        assert_eq!(Some("Blues"), palette.name());
+        assert_eq!(Blue::iter().count(), palette.len());
+        for (colour, entry) in Blue::iter().zip(palette.iter()) {
+            assert_eq!(colour.name(), entry.name());
+            assert_eq!(colour.to_rgb(), entry.colour());
+        }
+    }
+
+    #[test]
+    fn test_palette_round_trip() {
+        let mut palette = Palette::with_name("Brand");
+        palette.insert("Primary Blue", Rgb::new(18, 52, 86));
+        palette.insert("Accent", Rgb::new(255, 128, 0));
+
+        assert_eq!(palette, from_gpl(&palette_to_gpl(&palette)).unwrap());
+    }
+
+    #[test]
+    fn test_from_gpl_as_saved_by_gimp() {
+        let gpl = "GIMP Palette\r\n\
+                   Name: Web\r\n\
+                   Columns: 4\r\n\
+                   # A comment\r\n\
+                   \r\n\
+                   255 255 255\tWhite\r\n\
+                   70 130 180 Steel Blue (primary)\r\n\
+                   \t0\t0\t128\r\n";
+
+        let palette = from_gpl(gpl).unwrap();
+
+        assert_eq!(Some("Web"), palette.name());
+        assert_eq!(3, palette.len());
+        assert_eq!(Some(Rgb::new(255, 255, 255)), palette.get("White"));
+        assert_eq!(
+            Some(Rgb::new(70, 130, 180)),
+            palette.get("Steel Blue (primary)")
+        );
+        assert_eq!(Some(Rgb::new(0, 0, 128)), palette.get("#000080"));
+        assert_eq!(
+            "White",
+            palette.nearest(Rgb::new(250, 250, 250)).unwrap().name()
+        );
+    }
+
+    #[test]
+    fn test_from_gpl_keeps_repeated_names() {
+        let gpl = "GIMP Palette\n\
+                   Name: Imported\n\
+                   18 52 86\tUntitled\n\
+                   255 128 0\tUntitled\n";
+
+        let palette = from_gpl(gpl).unwrap();
+
+        assert_eq!(2, palette.len());
+        let colours: Vec<_> = palette.iter().map(PaletteEntry::colour).collect();
+        assert_eq!(vec![Rgb::new(18, 52, 86), Rgb::new(255, 128, 0)], colours);
+        assert_eq!(palette, from_gpl(&palette_to_gpl(&palette)).unwrap());
+    }
+
+    #[rstest]
+    #[case("")]
+    #[case("Name: Missing header\n0 0 0\tBlack\n")]
+    #[case("GIMP Palette\n0 0\tBlack\n")]
+    #[case("GIMP Palette\n0 0 256\tBlue\n")]
+    #[case("GIMP Palette\nzero 0 0\tBlack\n")]
+    fn test_from_gpl_invalid(#[case] gpl: &str) {
+        assert!(from_gpl(gpl).is_err());
+    }
+}
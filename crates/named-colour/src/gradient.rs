@@ -0,0 +1,500 @@
+//! Multi-stop gradients
+//!
+//! A [`Gradient`] is built from colour stops at positions from 0 to 1, and can
+//! be sampled at any position or written out as a CSS `linear-gradient()`.
+//! Between stops the colours are interpolated in a chosen colour space, using
+//! the same spaces and hue directions as CSS Color 4. Stops accept named
+//! colours as well as `Rgb<u8>`.
+//!
+//! ## Example
+//!
+//! ```
+//! use named_colour::gradient::{Gradient, HueDirection, Interpolation};
+//! use rgb::Rgb;
+//!
+//! let gradient = Gradient::new()
+//!     .stop(0.0, Rgb::new(255, 0, 0))
+//!     .stop(1.0, Rgb::new(0, 0, 255))
+//!     .interpolation(Interpolation::Hsl(HueDirection::Shorter));
+//!
+//! assert_eq!(Some(Rgb::new(255, 0, 255)), gradient.sample(0.5));
+//! assert_eq!(
+//!     "linear-gradient(90deg in hsl shorter hue, #FF0000 0%, #0000FF 100%)",
+//!     gradient.to_css(90.0)
+//! );
+//! ```
+//!
+
+use rgb::Rgb;
+
+use crate::lab::{lab_d50, rgb_from_lab_d50, Lab};
+use crate::oklab::Oklch;
+use crate::scale::{mix, Space};
+use crate::ToHex;
+
+/// Hues closer than this chroma or saturation to grey are ignored
+const ACHROMATIC: f64 = 0.000_1;
+
+/// The way round the colour wheel that hues are interpolated
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum HueDirection {
+    /// Take the shorter way round, at most 180°
+    #[default]
+    Shorter,
+    /// Take the longer way round, at least 180°
+    Longer,
+}
+
+/// The colour space in which a gradient is interpolated
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Interpolation {
+    /// The gamma encoded sRGB channels, the CSS default
+    #[default]
+    Srgb,
+    /// Linear light RGB
+    Linear,
+    /// Hue, saturation and lightness
+    Hsl(HueDirection),
+    /// CIELAB with the D50 white point, as CSS uses
+    Lab,
+    /// The polar form of OKLab
+    Oklch(HueDirection),
+}
+
+impl Interpolation {
+    /// The CSS colour interpolation method, or `None` for the default
+    fn css(&self) -> Option<String> {
+        let hue = |direction: &HueDirection| match direction {
+            HueDirection::Shorter => "shorter",
+            HueDirection::Longer => "longer",
+        };
+
+        match self {
+            Self::Srgb => None,
+            Self::Linear => Some("in srgb-linear".to_string()),
+            Self::Hsl(direction) => Some(format!("in hsl {} hue", hue(direction))),
+            Self::Lab => Some("in lab".to_string()),
+            Self::Oklch(direction) => Some(format!("in oklch {} hue", hue(direction))),
+        }
+    }
+
+    /// Interpolate between two colours, from the first at 0 to the second at 1
+    fn interpolate(&self, first: Rgb<u8>, second: Rgb<u8>, t: f64) -> Rgb<u8> {
+        match self {
+            Self::Srgb => mix(first, second, t, Space::Srgb),
+            Self::Linear => mix(first, second, t, Space::Linear),
+            Self::Hsl(direction) => {
+                let (a, b) = (Hsl::from(first), Hsl::from(second));
+                let (h, s, l) = polar(
+                    (a.h, a.s, a.l),
+                    (b.h, b.s, b.l),
+                    a.s < ACHROMATIC,
+                    b.s < ACHROMATIC,
+                    t,
+                    *direction,
+                );
+                Hsl { h, s, l }.into()
+            }
+            Self::Lab => {
+                let (a, b) = (lab_d50(first), lab_d50(second));
+                rgb_from_lab_d50(Lab::new(
+                    lerp(a.l, b.l, t),
+                    lerp(a.a, b.a, t),
+                    lerp(a.b, b.b, t),
+                ))
+            }
+            Self::Oklch(direction) => {
+                let (a, b) = (Oklch::from(first), Oklch::from(second));
+                let (h, c, l) = polar(
+                    (a.h, a.c, a.l),
+                    (b.h, b.c, b.l),
+                    a.c < ACHROMATIC,
+                    b.c < ACHROMATIC,
+                    t,
+                    *direction,
+                );
+                Oklch::new(l, c, h).into()
+            }
+        }
+    }
+}
+
+/// A colour stop of a gradient
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Stop {
+    /// The position of the stop, from 0 to 1
+    pub position: f64,
+    /// The colour at the stop
+    pub colour: Rgb<u8>,
+}
+
+/// A gradient through colour stops
+///
+/// Before the first stop the gradient has the colour of the first stop and
+/// after the last stop the colour of the last. Where two stops share a
+/// position the gradient changes colour sharply.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Gradient {
+    stops: Vec<Stop>,
+    interpolation: Interpolation,
+}
+
+impl Gradient {
+    /// Create a gradient with no stops, interpolated in sRGB
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a stop at a position, clamped between 0 and 1
+    ///
+    /// Stops are kept in order of position, and a stop added at the same
+    /// position as another comes after it. A stop at a position that is not a
+    /// number is ignored.
+    pub fn stop(mut self, position: f64, colour: impl Into<Rgb<u8>>) -> Self {
+        if position.is_nan() {
+            return self;
+        }
+        let position = position.clamp(0.0, 1.0);
+        let index = self.stops.partition_point(|stop| stop.position <= position);
+        self.stops.insert(
+            index,
+            Stop {
+                position,
+                colour: colour.into(),
+            },
+        );
+        self
+    }
+
+    /// Interpolate in the given colour space
+    pub fn interpolation(mut self, interpolation: Interpolation) -> Self {
+        self.interpolation = interpolation;
+        self
+    }
+
+    /// The stops of the gradient, in order of position
+    pub fn stops(&self) -> &[Stop] {
+        &self.stops
+    }
+
+    /// The colour of the gradient at a position from 0 to 1
+    ///
+    /// Returns `None` if the gradient has no stops or the position is not a
+    /// number.
+    pub fn sample(&self, t: f64) -> Option<Rgb<u8>> {
+        if t.is_nan() {
+            return None;
+        }
+        let first = self.stops.first()?;
+        let last = self.stops.last()?;
+        if t <= first.position {
+            return Some(first.colour);
+        }
+        if t >= last.position {
+            return Some(last.colour);
+        }
+
+        let index = self.stops.partition_point(|stop| stop.position <= t);
+        let (before, after) = (&self.stops[index - 1], &self.stops[index]);
+        let local = (t - before.position) / (after.position - before.position);
+
+        Some(
+            self.interpolation
+                .interpolate(before.colour, after.colour, local),
+        )
+    }
+
+    /// Sample the gradient at `count` evenly spaced positions from 0 to 1
+    pub fn samples(&self, count: usize) -> Vec<Rgb<u8>> {
+        (0..count)
+            .filter_map(|i| {
+                let t = if count == 1 {
+                    0.0
+                } else {
+                    i as f64 / (count - 1) as f64
+                };
+                self.sample(t)
+            })
+            .collect()
+    }
+
+    /// The gradient as a CSS `linear-gradient()` at an angle in degrees
+    ///
+    /// An angle of 180 runs from top to bottom, the CSS default.
+    pub fn to_css(&self, degrees: f64) -> String {
+        let mut method = format!("{degrees}deg");
+        if let Some(interpolation) = self.interpolation.css() {
+            method = format!("{method} {interpolation}");
+        }
+
+        let stops = self.stops.iter().map(|stop| {
+            let percent = (stop.position * 10_000.0).round() / 100.0;
+            format!("{} {percent}%", stop.colour.as_hex())
+        });
+
+        format!(
+            "linear-gradient({})",
+            std::iter::once(method)
+                .chain(stops)
+                .collect::<Vec<_>>()
+                .join(", ")
+        )
+    }
+}
+
+fn lerp(a: f64, b: f64, t: f64) -> f64 {
+    a + (b - a) * t
+}
+
+/// Interpolate a polar colour of hue, radius and lightness, following CSS
+/// Color 4: a colour with no hue takes the hue of the other colour
+fn polar(
+    first: (f64, f64, f64),
+    second: (f64, f64, f64),
+    first_grey: bool,
+    second_grey: bool,
+    t: f64,
+    direction: HueDirection,
+) -> (f64, f64, f64) {
+    let (mut h1, mut h2) = (first.0, second.0);
+    if first_grey {
+        h1 = h2;
+    }
+    if second_grey {
+        h2 = h1;
+    }
+
+    let d = h2 - h1;
+    match direction {
+        HueDirection::Shorter if d > 180.0 => h1 += 360.0,
+        HueDirection::Shorter if d < -180.0 => h2 += 360.0,
+        HueDirection::Longer if 0.0 < d && d < 180.0 => h1 += 360.0,
+        HueDirection::Longer if -180.0 < d && d <= 0.0 => h2 += 360.0,
+        _ => {}
+    }
+
+    (
+        lerp(h1, h2, t).rem_euclid(360.0),
+        lerp(first.1, second.1, t),
+        lerp(first.2, second.2, t),
+    )
+}
+
+/// A colour as hue in degrees, and saturation and lightness from 0 to 1
+struct Hsl {
+    h: f64,
+    s: f64,
+    l: f64,
+}
+
+impl From<Rgb<u8>> for Hsl {
+    fn from(rgb: Rgb<u8>) -> Self {
+        let (r, g, b) = (
+            rgb.r as f64 / 255.0,
+            rgb.g as f64 / 255.0,
+            rgb.b as f64 / 255.0,
+        );
+        let max = r.max(g).max(b);
+        let min = r.min(g).min(b);
+        let l = (max + min) / 2.0;
+        let d = max - min;
+
+        if d == 0.0 {
+            return Self { h: 0.0, s: 0.0, l };
+        }
+
+        let s = d / (1.0 - (2.0 * l - 1.0).abs());
+        let h = if max == r {
+            (g - b) / d
+        } else if max == g {
+            (b - r) / d + 2.0
+        } else {
+            (r - g) / d + 4.0
+        };
+
+        Self {
+            h: (h * 60.0).rem_euclid(360.0),
+            s,
+            l,
+        }
+    }
+}
+
+impl From<Hsl> for Rgb<u8> {
+    fn from(hsl: Hsl) -> Self {
+        let channel = |n: f64| {
+            let k = (n + hsl.h / 30.0) % 12.0;
+            let a = hsl.s * hsl.l.min(1.0 - hsl.l);
+            let c = hsl.l - a * (k - 3.0).min(9.0 - k).clamp(-1.0, 1.0);
+            (c.clamp(0.0, 1.0) * 255.0).round() as u8
+        };
+
+        Rgb::new(channel(0.0), channel(8.0), channel(4.0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    const RED: Rgb<u8> = Rgb::new(255, 0, 0);
+    const GREEN: Rgb<u8> = Rgb::new(0, 255, 0);
+    const BLUE: Rgb<u8> = Rgb::new(0, 0, 255);
+    const WHITE: Rgb<u8> = Rgb::new(255, 255, 255);
+
+    fn red_to_blue(interpolation: Interpolation) -> Gradient {
+        Gradient::new()
+            .stop(0.0, RED)
+            .stop(1.0, BLUE)
+            .interpolation(interpolation)
+    }
+
+    #[rstest]
+    #[case(Interpolation::Srgb, Rgb::new(128, 0, 128))]
+    #[case(Interpolation::Linear, Rgb::new(188, 0, 188))]
+    #[case(Interpolation::Hsl(HueDirection::Shorter), Rgb::new(255, 0, 255))]
+    #[case(Interpolation::Hsl(HueDirection::Longer), Rgb::new(0, 255, 0))]
+    #[case(Interpolation::Lab, Rgb::new(193, 0, 136))]
+    #[case(Interpolation::Oklch(HueDirection::Shorter), Rgb::new(178, 0, 184))]
+    fn test_sample_midpoint(#[case] interpolation: Interpolation, #[case] expected: Rgb<u8>) {
+        assert_eq!(Some(expected), red_to_blue(interpolation).sample(0.5));
+    }
+
+    #[rstest]
+    #[case(Interpolation::Srgb)]
+    #[case(Interpolation::Linear)]
+    #[case(Interpolation::Hsl(HueDirection::Longer))]
+    #[case(Interpolation::Lab)]
+    #[case(Interpolation::Oklch(HueDirection::Longer))]
+    fn test_sample_ends(#[case] interpolation: Interpolation) {
+        let gradient = red_to_blue(interpolation);
+
+        assert_eq!(Some(RED), gradient.sample(0.0));
+        assert_eq!(Some(RED), gradient.sample(-1.0));
+        assert_eq!(Some(BLUE), gradient.sample(1.0));
+        assert_eq!(Some(BLUE), gradient.sample(0.999_999));
+        assert_eq!(Some(BLUE), gradient.sample(2.0));
+    }
+
+    #[test]
+    fn test_multiple_stops() {
+        let gradient = Gradient::new()
+            .stop(1.0, BLUE)
+            .stop(0.0, RED)
+            .stop(0.25, GREEN);
+
+        let positions: Vec<f64> = gradient.stops().iter().map(|stop| stop.position).collect();
+        assert_eq!(vec![0.0, 0.25, 1.0], positions);
+        assert_eq!(Some(GREEN), gradient.sample(0.25));
+        assert_eq!(Some(Rgb::new(0, 128, 128)), gradient.sample(0.625));
+    }
+
+    #[test]
+    fn test_hard_stop() {
+        let gradient = Gradient::new()
+            .stop(0.0, RED)
+            .stop(0.5, RED)
+            .stop(0.5, BLUE)
+            .stop(1.0, BLUE);
+
+        assert_eq!(Some(RED), gradient.sample(0.49));
+        assert_eq!(Some(BLUE), gradient.sample(0.5));
+    }
+
+    #[test]
+    fn test_nan_positions() {
+        let gradient = red_to_blue(Interpolation::Srgb).stop(f64::NAN, GREEN);
+
+        assert_eq!(2, gradient.stops().len());
+        assert_eq!(None, gradient.sample(f64::NAN));
+        assert_eq!(Some(Rgb::new(128, 0, 128)), gradient.sample(0.5));
+    }
+
+    #[test]
+    fn test_empty_gradient() {
+        let gradient = Gradient::new();
+
+        assert_eq!(None, gradient.sample(0.5));
+        assert!(gradient.samples(3).is_empty());
+    }
+
+    #[test]
+    fn test_samples() {
+        let gradient = red_to_blue(Interpolation::Srgb);
+
+        assert_eq!(vec![RED, Rgb::new(128, 0, 128), BLUE], gradient.samples(3));
+        assert_eq!(vec![RED], gradient.samples(1));
+    }
+
+    #[rstest]
+    #[case(Interpolation::Hsl(HueDirection::Shorter), Rgb::new(159, 159, 223))]
+    #[case(Interpolation::Oklch(HueDirection::Shorter), Rgb::new(121, 164, 255))]
+    fn test_grey_takes_the_other_hue(
+        #[case] interpolation: Interpolation,
+        #[case] expected: Rgb<u8>,
+    ) {
+        let gradient = Gradient::new()
+            .stop(0.0, WHITE)
+            .stop(1.0, BLUE)
+            .interpolation(interpolation);
+
+        assert_eq!(Some(expected), gradient.sample(0.5));
+    }
+
+    #[rstest]
+    #[case(
+        Interpolation::Srgb,
+        "linear-gradient(180deg, #FF0000 0%, #0000FF 100%)"
+    )]
+    #[case(
+        Interpolation::Linear,
+        "linear-gradient(180deg in srgb-linear, #FF0000 0%, #0000FF 100%)"
+    )]
+    #[case(
+        Interpolation::Lab,
+        "linear-gradient(180deg in lab, #FF0000 0%, #0000FF 100%)"
+    )]
+    #[case(
+        Interpolation::Oklch(HueDirection::Longer),
+        "linear-gradient(180deg in oklch longer hue, #FF0000 0%, #0000FF 100%)"
+    )]
+    fn test_to_css(#[case] interpolation: Interpolation, #[case] expected: &str) {
+        assert_eq!(expected, red_to_blue(interpolation).to_css(180.0));
+    }
+
+    #[test]
+    fn test_to_css_positions() {
+        let gradient = Gradient::new().stop(0.125, RED).stop(1.0 / 3.0, BLUE);
+
+        assert_eq!(
+            "linear-gradient(45deg, #FF0000 12.5%, #0000FF 33.33%)",
+            gradient.to_css(45.0)
+        );
+    }
+
+    #[rstest]
+    #[case(Rgb::new(255, 0, 0))]
+    #[case(Rgb::new(70, 130, 180))]
+    #[case(Rgb::new(128, 128, 128))]
+    #[case(Rgb::new(255, 250, 240))]
+    fn test_hsl_round_trip(#[case] rgb: Rgb<u8>) {
+        assert_eq!(rgb, Rgb::from(Hsl::from(rgb)));
+    }
+
+    #[cfg(feature = "extended")]
+    #[test]
+    fn test_named_stops() {
+        use crate::ext::{Blue, Cyan};
+
+        let gradient = Gradient::new()
+            .stop(0.0, Blue::SteelBlue)
+            .stop(1.0, Cyan::Teal);
+
+        assert_eq!(Some(Blue::SteelBlue.to_rgb()), gradient.sample(0.0));
+        assert_eq!(
+            "linear-gradient(90deg, #4682B4 0%, #008080 100%)",
+            gradient.to_css(90.0)
+        );
+    }
+}
@@ -0,0 +1,255 @@
+//! Colour harmonies
+//!
+//! Harmonies are sets of colours found by rotating the hue of a base colour
+//! around the OKLCH colour wheel, keeping its lightness and chroma. The base
+//! colour always comes first in the set. Every function accepts named colours
+//! as well as `Rgb<u8>`, and with the `extended` feature each named colour has
+//! the same harmonies as methods, returning the nearest named colour for each
+//! member as well.
+//!
+//! ## Example
+//!
+//! ```
+//! use named_colour::harmony::triadic;
+//! use rgb::Rgb;
+//!
+//! let [base, second, third] = triadic(Rgb::new(70, 130, 180));
+//!
+//! assert_eq!(Rgb::new(70, 130, 180), base);
+//! assert_eq!(Rgb::new(174, 98, 116), second);
+//! assert_eq!(Rgb::new(110, 135, 66), third);
+//! ```
+//!
+//! With the `extended` feature:
+//!
+//! ```
+//! # #[cfg(feature = "extended")]
+//! # {
+//! use named_colour::ext::Blue;
+//! use rgb::Rgb;
+//!
+//! let [base, second, third] = Blue::SteelBlue.triadic();
+//!
+//! assert_eq!("SteelBlue", base.named.name());
+//! assert_eq!(Rgb::new(174, 98, 116), second.rgb);
+//! assert_eq!("IndianRed", second.named.name());
+//! assert_eq!("OliveDrab", third.named.name());
+//! # }
+//! ```
+//!
+
+use rgb::Rgb;
+
+use crate::oklab::Oklch;
+
+/// Rotate the hue of a colour by an angle in degrees
+pub fn rotate_hue(colour: impl Into<Rgb<u8>>, degrees: f64) -> Rgb<u8> {
+    rotations(colour, [degrees])[0]
+}
+
+/// The colour and the colour opposite it on the colour wheel
+pub fn complementary(colour: impl Into<Rgb<u8>>) -> [Rgb<u8>; 2] {
+    rotations(colour, [0.0, 180.0])
+}
+
+/// The colour and the two colours a third of the way round the colour wheel
+/// from it
+pub fn triadic(colour: impl Into<Rgb<u8>>) -> [Rgb<u8>; 3] {
+    rotations(colour, [0.0, 120.0, 240.0])
+}
+
+/// The colour and its neighbours 30° either side on the colour wheel
+pub fn analogous(colour: impl Into<Rgb<u8>>) -> [Rgb<u8>; 3] {
+    rotations(colour, [0.0, -30.0, 30.0])
+}
+
+/// The colour and the two colours 30° either side of its complement
+pub fn split_complementary(colour: impl Into<Rgb<u8>>) -> [Rgb<u8>; 3] {
+    rotations(colour, [0.0, 150.0, 210.0])
+}
+
+/// The colour and three others forming a rectangle on the colour wheel: its
+/// neighbour 60° round, and the complements of both
+pub fn tetradic(colour: impl Into<Rgb<u8>>) -> [Rgb<u8>; 4] {
+    rotations(colour, [0.0, 60.0, 180.0, 240.0])
+}
+
+/// Rotate the hue of a colour by each of the angles, leaving the colour
+/// unchanged for an angle of zero
+fn rotations<const N: usize>(colour: impl Into<Rgb<u8>>, degrees: [f64; N]) -> [Rgb<u8>; N] {
+    let rgb = colour.into();
+    let lch = Oklch::from(rgb);
+
+    degrees.map(|degrees| {
+        if degrees == 0.0 {
+            rgb
+        } else {
+            Oklch::new(lch.l, lch.c, (lch.h + degrees).rem_euclid(360.0)).into()
+        }
+    })
+}
+
+#[cfg(feature = "extended")]
+pub use named::HarmonyColour;
+
+#[cfg(feature = "extended")]
+mod named {
+    use rgb::Rgb;
+
+    use crate::ext::{Black, Blue, Brown, Cyan, Green, NamedColour, Purple, Red, White, Yellow};
+
+    /// A member of a harmony of a named colour
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub struct HarmonyColour {
+        /// The exact colour of the member
+        pub rgb: Rgb<u8>,
+        /// The named colour nearest to the member
+        pub named: NamedColour,
+    }
+
+    impl HarmonyColour {
+        fn from_rgb(rgb: Rgb<u8>) -> Self {
+            Self {
+                rgb,
+                named: NamedColour::nearest(rgb),
+            }
+        }
+    }
+
+    /// Add the harmonies as methods of named colours, keeping the colour
+    /// itself as the named colour of the first member
+    macro_rules! impl_harmonies {
+        ($($colour:ty),+) => {$(
+            impl $colour {
+                /// The colour and its complement, with their nearest named colours
+                pub fn complementary(&self) -> [HarmonyColour; 2] {
+                    self.harmony(super::complementary(*self))
+                }
+
+                /// The triadic harmony of the colour, with the nearest named colours
+                pub fn triadic(&self) -> [HarmonyColour; 3] {
+                    self.harmony(super::triadic(*self))
+                }
+
+                /// The analogous harmony of the colour, with the nearest named colours
+                pub fn analogous(&self) -> [HarmonyColour; 3] {
+                    self.harmony(super::analogous(*self))
+                }
+
+                /// The split complementary harmony of the colour, with the nearest
+                /// named colours
+                pub fn split_complementary(&self) -> [HarmonyColour; 3] {
+                    self.harmony(super::split_complementary(*self))
+                }
+
+                /// The tetradic harmony of the colour, with the nearest named colours
+                pub fn tetradic(&self) -> [HarmonyColour; 4] {
+                    self.harmony(super::tetradic(*self))
+                }
+
+                fn harmony<const N: usize>(&self, rgbs: [Rgb<u8>; N]) -> [HarmonyColour; N] {
+                    let mut members = rgbs.map(HarmonyColour::from_rgb);
+                    members[0].named = NamedColour::from(*self);
+                    members
+                }
+            }
+        )+};
+    }
+
+    impl_harmonies!(
+        NamedColour,
+        Black,
+        Blue,
+        Brown,
+        Cyan,
+        Green,
+        Purple,
+        Red,
+        White,
+        Yellow
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::oklab::Oklch;
+    use rstest::rstest;
+
+    fn hue(rgb: Rgb<u8>) -> f64 {
+        Oklch::from(rgb).h
+    }
+
+    /// The angle between two hues, from 0 to 180
+    fn angle(a: f64, b: f64) -> f64 {
+        let d = (a - b).rem_euclid(360.0);
+        d.min(360.0 - d)
+    }
+
+    #[rstest]
+    #[case(Rgb::new(70, 130, 180))]
+    #[case(Rgb::new(200, 60, 60))]
+    #[case(Rgb::new(128, 128, 0))]
+    fn test_members_are_rotated(#[case] colour: Rgb<u8>) {
+        let base = hue(colour);
+
+        let [first, complement] = complementary(colour);
+        assert_eq!(colour, first);
+        assert!((180.0 - angle(base, hue(complement))).abs() < 3.0);
+
+        for member in &triadic(colour)[1..] {
+            assert!((120.0 - angle(base, hue(*member))).abs() < 3.0);
+        }
+        for member in &analogous(colour)[1..] {
+            assert!((30.0 - angle(base, hue(*member))).abs() < 3.0);
+        }
+        for member in &split_complementary(colour)[1..] {
+            assert!((150.0 - angle(base, hue(*member))).abs() < 3.0);
+        }
+
+        let [_, second, third, fourth] = tetradic(colour);
+        assert!((60.0 - angle(base, hue(second))).abs() < 3.0);
+        assert!((180.0 - angle(base, hue(third))).abs() < 3.0);
+        assert!((120.0 - angle(base, hue(fourth))).abs() < 3.0);
+    }
+
+    #[test]
+    fn test_rotate_hue_full_turn() {
+        let colour = Rgb::new(70, 130, 180);
+
+        assert_eq!(colour, rotate_hue(colour, 360.0));
+        assert_eq!(complementary(colour)[1], rotate_hue(colour, -180.0));
+    }
+
+    #[test]
+    fn test_grey_has_grey_harmonies() {
+        let grey = Rgb::new(128, 128, 128);
+
+        assert!(tetradic(grey).iter().all(|&member| member == grey));
+    }
+
+    #[cfg(feature = "extended")]
+    #[test]
+    fn test_named_harmonies() {
+        use crate::ext::{Blue, NamedColour};
+
+        let members = NamedColour::from(Blue::SteelBlue).tetradic();
+        let from_family = Blue::SteelBlue.tetradic();
+
+        assert_eq!(members, from_family);
+        assert_eq!(tetradic(Blue::SteelBlue), members.map(|member| member.rgb));
+        for member in &members[1..] {
+            assert_eq!(NamedColour::nearest(member.rgb), member.named);
+        }
+    }
+
+    #[cfg(feature = "extended")]
+    #[test]
+    fn test_named_harmony_keeps_alias() {
+        use crate::ext::{Black, NamedColour};
+
+        let [base, _] = Black::Grey.complementary();
+
+        assert_eq!(NamedColour::from(Black::Grey), base.named);
+    }
+}
@@ -0,0 +1,299 @@
+//! CIELAB colours and perceptual colour difference
+//!
+//! RGB values are taken as sRGB and converted to CIELAB with the D65 white
+//! point. The difference between two colours is measured with CIEDE2000, where
+//! a ΔE of about 2 is just noticeable and colours with a ΔE above 10 are
+//! clearly distinct.
+//!
+//! ## Example
+//!
+//! ```
+//! use named_colour::lab::{delta_e, Lab};
+//! use rgb::Rgb;
+//!
+//! let red = Lab::from(Rgb::new(255, 0, 0));
+//! assert_eq!(53, red.l.round() as i32);
+//!
+//! assert!(delta_e(Rgb::new(255, 0, 0), Rgb::new(250, 5, 5)) < 2.0);
+//! assert!(delta_e(Rgb::new(255, 0, 0), Rgb::new(0, 0, 255)) > 10.0);
+//! ```
+//!
+
+use rgb::Rgb;
+
+/// The XYZ tristimulus values of the D65 white point
+const D65: [f64; 3] = [0.950_47, 1.0, 1.088_83];
+/// The XYZ tristimulus values of the D50 white point
+const D50: [f64; 3] = [0.964_22, 1.0, 0.825_21];
+
+/// A colour in the CIELAB colour space
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Lab {
+    /// Lightness, from 0 for black to 100 for white
+    pub l: f64,
+    /// Position between green (negative) and red (positive)
+    pub a: f64,
+    /// Position between blue (negative) and yellow (positive)
+    pub b: f64,
+}
+
+impl Lab {
+    /// Create a colour from its lightness and `a` and `b` components
+    pub const fn new(l: f64, a: f64, b: f64) -> Self {
+        Self { l, a, b }
+    }
+
+    /// The CIEDE2000 difference between two colours
+    pub fn delta_e(&self, other: &Lab) -> f64 {
+        ciede2000(self, other)
+    }
+
+    /// Convert XYZ tristimulus values relative to a white point
+    fn from_xyz([x, y, z]: [f64; 3], white: [f64; 3]) -> Self {
+        let (fx, fy, fz) = (f(x / white[0]), f(y / white[1]), f(z / white[2]));
+        Self {
+            l: 116.0 * fy - 16.0,
+            a: 500.0 * (fx - fy),
+            b: 200.0 * (fy - fz),
+        }
+    }
+
+    /// Convert to XYZ tristimulus values relative to a white point
+    fn to_xyz(self, white: [f64; 3]) -> [f64; 3] {
+        let fy = (self.l + 16.0) / 116.0;
+        [
+            f_inverse(fy + self.a / 500.0) * white[0],
+            f_inverse(fy) * white[1],
+            f_inverse(fy - self.b / 200.0) * white[2],
+        ]
+    }
+}
+
+impl From<Rgb<u8>> for Lab {
+    fn from(rgb: Rgb<u8>) -> Self {
+        let (r, g, b) = (linear(rgb.r), linear(rgb.g), linear(rgb.b));
+
+        // sRGB to XYZ, relative to the D65 white point
+        let xyz = [
+            0.412_456_4 * r + 0.357_576_1 * g + 0.180_437_5 * b,
+            0.212_672_9 * r + 0.715_152_2 * g + 0.072_175_0 * b,
+            0.019_333_9 * r + 0.119_192 * g + 0.950_304_1 * b,
+        ];
+
+        Self::from_xyz(xyz, D65)
+    }
+}
+
+impl From<Lab> for Rgb<u8> {
+    fn from(lab: Lab) -> Self {
+        let [x, y, z] = lab.to_xyz(D65);
+
+        // XYZ to linear sRGB, clipping colours outside the gamut
+        let r = 3.240_454_2 * x - 1.537_138_5 * y - 0.498_531_4 * z;
+        let g = -0.969_266 * x + 1.876_010_8 * y + 0.041_556 * z;
+        let b = 0.055_643_4 * x - 0.204_025_9 * y + 1.057_225_2 * z;
+
+        Rgb::new(encode(r), encode(g), encode(b))
+    }
+}
+
+/// Convert an sRGB colour to CIELAB relative to the D50 white point, as used
+/// by CSS `lab()`
+///
+/// The colour is adapted from D65 to D50 with the Bradford transform.
+pub(crate) fn lab_d50(rgb: Rgb<u8>) -> Lab {
+    let (r, g, b) = (linear(rgb.r), linear(rgb.g), linear(rgb.b));
+
+    let xyz = [
+        0.436_074_7 * r + 0.385_064_9 * g + 0.143_080_4 * b,
+        0.222_504_5 * r + 0.716_878_6 * g + 0.060_616_9 * b,
+        0.013_932_2 * r + 0.097_104_5 * g + 0.714_173_3 * b,
+    ];
+
+    Lab::from_xyz(xyz, D50)
+}
+
+/// Convert a CIELAB colour relative to the D50 white point to sRGB, clipping
+/// colours outside the gamut
+pub(crate) fn rgb_from_lab_d50(lab: Lab) -> Rgb<u8> {
+    let [x, y, z] = lab.to_xyz(D50);
+
+    let r = 3.133_856_1 * x - 1.616_866_7 * y - 0.490_614_6 * z;
+    let g = -0.978_768_4 * x + 1.916_141_5 * y + 0.033_454 * z;
+    let b = 0.071_945_3 * x - 0.228_991_4 * y + 1.405_242_7 * z;
+
+    Rgb::new(encode(r), encode(g), encode(b))
+}
+
+/// The CIEDE2000 difference between two RGB colours
+///
+/// Accepts named colours as well as `Rgb<u8>`.
+pub fn delta_e(a: impl Into<Rgb<u8>>, b: impl Into<Rgb<u8>>) -> f64 {
+    Lab::from(a.into()).delta_e(&Lab::from(b.into()))
+}
+
+/// Convert an sRGB channel to linear light between 0 and 1
+pub(crate) fn linear(channel: u8) -> f64 {
+    let c = channel as f64 / 255.0;
+    if c <= 0.040_45 {
+        c / 12.92
+    } else {
+        ((c + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+/// Convert linear light to an sRGB channel
+pub(crate) fn encode(linear: f64) -> u8 {
+    let c = linear.clamp(0.0, 1.0);
+    let c = if c <= 0.003_130_8 {
+        12.92 * c
+    } else {
+        1.055 * c.powf(1.0 / 2.4) - 0.055
+    };
+    (c * 255.0).round() as u8
+}
+
+fn f(t: f64) -> f64 {
+    const DELTA: f64 = 6.0 / 29.0;
+    if t > DELTA * DELTA * DELTA {
+        t.cbrt()
+    } else {
+        t / (3.0 * DELTA * DELTA) + 4.0 / 29.0
+    }
+}
+
+fn f_inverse(t: f64) -> f64 {
+    const DELTA: f64 = 6.0 / 29.0;
+    if t > DELTA {
+        t * t * t
+    } else {
+        3.0 * DELTA * DELTA * (t - 4.0 / 29.0)
+    }
+}
+
+/// CIEDE2000, following Sharma, Wu and Dalal (2005)
+fn ciede2000(first: &Lab, second: &Lab) -> f64 {
+    const POW25_7: f64 = 6_103_515_625.0;
+
+    let c1 = first.a.hypot(first.b);
+    let c2 = second.a.hypot(second.b);
+    let c_bar7 = ((c1 + c2) / 2.0).powi(7);
+    let g = 0.5 * (1.0 - (c_bar7 / (c_bar7 + POW25_7)).sqrt());
+
+    let a1 = (1.0 + g) * first.a;
+    let a2 = (1.0 + g) * second.a;
+    let c1 = a1.hypot(first.b);
+    let c2 = a2.hypot(second.b);
+    let h1 = hue(a1, first.b);
+    let h2 = hue(a2, second.b);
+
+    let delta_l = second.l - first.l;
+    let delta_c = c2 - c1;
+    let delta_h = if c1 * c2 == 0.0 {
+        0.0
+    } else if (h2 - h1).abs() <= 180.0 {
+        h2 - h1
+    } else if h2 <= h1 {
+        h2 - h1 + 360.0
+    } else {
+        h2 - h1 - 360.0
+    };
+    let delta_h = 2.0 * (c1 * c2).sqrt() * (delta_h / 2.0).to_radians().sin();
+
+    let l_bar = (first.l + second.l) / 2.0;
+    let c_bar = (c1 + c2) / 2.0;
+    let h_bar = if c1 * c2 == 0.0 {
+        h1 + h2
+    } else if (h1 - h2).abs() <= 180.0 {
+        (h1 + h2) / 2.0
+    } else if h1 + h2 < 360.0 {
+        (h1 + h2 + 360.0) / 2.0
+    } else {
+        (h1 + h2 - 360.0) / 2.0
+    };
+
+    let t = 1.0 - 0.17 * (h_bar - 30.0).to_radians().cos()
+        + 0.24 * (2.0 * h_bar).to_radians().cos()
+        + 0.32 * (3.0 * h_bar + 6.0).to_radians().cos()
+        - 0.20 * (4.0 * h_bar - 63.0).to_radians().cos();
+    let delta_theta = 30.0 * (-((h_bar - 275.0) / 25.0).powi(2)).exp();
+    let c_bar7 = c_bar.powi(7);
+    let r_c = 2.0 * (c_bar7 / (c_bar7 + POW25_7)).sqrt();
+    let s_l = 1.0 + 0.015 * (l_bar - 50.0).powi(2) / (20.0 + (l_bar - 50.0).powi(2)).sqrt();
+    let s_c = 1.0 + 0.045 * c_bar;
+    let s_h = 1.0 + 0.015 * c_bar * t;
+    let r_t = -(2.0 * delta_theta).to_radians().sin() * r_c;
+
+    let (l, c, h) = (delta_l / s_l, delta_c / s_c, delta_h / s_h);
+    (l * l + c * c + h * h + r_t * c * h).sqrt()
+}
+
+/// The hue angle in degrees between 0 and 360
+fn hue(a: f64, b: f64) -> f64 {
+    if a == 0.0 && b == 0.0 {
+        0.0
+    } else {
+        b.atan2(a).to_degrees().rem_euclid(360.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(Rgb::new(255, 255, 255), Lab::new(100.0, 0.0, 0.0))]
+    #[case(Rgb::new(0, 0, 0), Lab::new(0.0, 0.0, 0.0))]
+    #[case(Rgb::new(255, 0, 0), Lab::new(53.24, 80.09, 67.20))]
+    #[case(Rgb::new(70, 130, 180), Lab::new(52.47, -4.08, -32.19))]
+    fn test_lab_from_rgb(#[case] rgb: Rgb<u8>, #[case] expected: Lab) {
+        let lab = Lab::from(rgb);
+
+        assert!((expected.l - lab.l).abs() < 0.01, "{lab:?}");
+        assert!((expected.a - lab.a).abs() < 0.01, "{lab:?}");
+        assert!((expected.b - lab.b).abs() < 0.01, "{lab:?}");
+    }
+
+    #[rstest]
+    #[case(Rgb::new(0, 0, 0))]
+    #[case(Rgb::new(255, 255, 255))]
+    #[case(Rgb::new(255, 0, 0))]
+    #[case(Rgb::new(70, 130, 180))]
+    #[case(Rgb::new(3, 2, 1))]
+    fn test_rgb_from_lab(#[case] rgb: Rgb<u8>) {
+        assert_eq!(rgb, Rgb::from(Lab::from(rgb)));
+    }
+
+    // CSS Color 4 values of lab() for the sRGB colours
+    #[rstest]
+    #[case(Rgb::new(255, 255, 255), Lab::new(100.0, 0.0, 0.0))]
+    #[case(Rgb::new(255, 0, 0), Lab::new(54.29, 80.80, 69.89))]
+    #[case(Rgb::new(0, 0, 255), Lab::new(29.57, 68.29, -112.03))]
+    fn test_lab_d50(#[case] rgb: Rgb<u8>, #[case] expected: Lab) {
+        let lab = lab_d50(rgb);
+
+        assert!((expected.l - lab.l).abs() < 0.05, "{lab:?}");
+        assert!((expected.a - lab.a).abs() < 0.05, "{lab:?}");
+        assert!((expected.b - lab.b).abs() < 0.05, "{lab:?}");
+        assert_eq!(rgb, rgb_from_lab_d50(lab));
+    }
+
+    // Test data from Sharma, Wu and Dalal (2005)
+    #[rstest]
+    #[case(Lab::new(50.0, 2.6772, -79.7751), Lab::new(50.0, 0.0, -82.7485), 2.0425)]
+    #[case(Lab::new(50.0, 2.5, 0.0), Lab::new(50.0, 0.0, -2.5), 4.3065)]
+    #[case(Lab::new(50.0, 2.5, 0.0), Lab::new(73.0, 25.0, -18.0), 27.1492)]
+    #[case(Lab::new(50.0, -0.001, 2.49), Lab::new(50.0, 0.0011, -2.4900), 4.7461)]
+    #[case(Lab::new(60.2574, -34.0099, 36.2677), Lab::new(60.4626, -34.1751, 39.4387), 1.2644)]
+    #[case(Lab::new(2.0776, 0.0795, -1.135), Lab::new(0.9033, -0.0636, -0.5514), 0.9082)]
+    fn test_ciede2000(#[case] first: Lab, #[case] second: Lab, #[case] expected: f64) {
+        assert!((expected - first.delta_e(&second)).abs() < 0.0001);
+        assert!((expected - second.delta_e(&first)).abs() < 0.0001);
+    }
+
+    #[test]
+    fn test_delta_e_of_same_colour_is_zero() {
+        assert_eq!(0.0, delta_e(Rgb::new(70, 130, 180), Rgb::new(70, 130, 180)));
+    }
+}
@@ -81,24 +81,67 @@ named-colour = { version = "0.3.26", features = ["extended"]}
 ///
 ///
 ///
+#[macro_use]
+mod macros;
+
+mod literal;
+mod table;
 mod to_hex;
 
+#[cfg(feature = "extended")]
+pub mod adobe;
+pub mod apca;
 #[cfg(not(feature = "extended"))]
 mod basic;
+pub mod blend;
+#[cfg(feature = "extended")]
+mod colour;
+pub mod consts;
+pub mod contrast;
+pub mod cvd;
+#[cfg(feature = "extended")]
+pub mod export;
 #[cfg(feature = "extended")]
 pub mod ext;
+#[cfg(feature = "extended")]
+pub mod gpl;
+pub mod gradient;
+pub mod harmony;
+pub mod lab;
+#[cfg(feature = "extended")]
+pub mod ls_colors;
+pub mod oklab;
+#[cfg(feature = "extended")]
+pub mod palette;
+pub mod random;
+pub mod scale;
+pub mod temperature;
 
+#[doc(hidden)]
+pub use crate::literal::private as __private;
 pub use crate::to_hex::ToHex;
 pub use rgb::RGB8;
 
 #[cfg(not(feature = "extended"))]
 pub use basic::Basic;
 
+#[cfg(feature = "extended")]
+pub use colour::Colour;
+#[cfg(feature = "extended")]
+pub use ext::all_named_colours;
+#[cfg(feature = "extended")]
+pub use ext::classify_family;
+#[cfg(feature = "extended")]
+pub use ext::lookup;
+#[cfg(feature = "extended")]
+pub use ext::lookup_all;
 #[cfg(feature = "extended")]
 pub use ext::name_colour;
 #[cfg(feature = "extended")]
 pub use ext::random_named_colour;
 #[cfg(feature = "extended")]
+pub use ext::random_named_colour_with;
+#[cfg(feature = "extended")]
 pub use ext::Black;
 #[cfg(feature = "extended")]
 pub use ext::Blue;
@@ -109,8 +152,12 @@ pub use ext::Cyan;
 #[cfg(feature = "extended")]
 pub use ext::ExtendedColour;
 #[cfg(feature = "extended")]
+pub use ext::Family;
+#[cfg(feature = "extended")]
 pub use ext::Green;
 #[cfg(feature = "extended")]
+pub use ext::NamedColour;
+#[cfg(feature = "extended")]
 pub use ext::Purple;
 #[cfg(feature = "extended")]
 pub use ext::Red;
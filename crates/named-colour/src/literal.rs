@@ -0,0 +1,167 @@
+//! Macros checking colour names and hex codes when the crate using them is
+//! compiled
+//!
+
+/// A named colour, checked when the crate is compiled
+///
+/// The name is matched ignoring ASCII case and the macro expands to a
+/// constant, so `colour!("darkorchid")` is `NamedColour::Purple(Purple::DarkOrchid)`
+/// with the `extended` feature and a `Basic` colour without it. Use
+/// `.to_rgb()` for the `RGB8`, which is also a `const fn`.
+///
+/// An unknown name fails to compile, naming the colour that was not found.
+///
+/// ## Example
+///
+/// ```
+/// # use named_colour::{colour, RGB8};
+///     const ACCENT: RGB8 = colour!("teal").to_rgb();
+///
+///     assert_eq!(RGB8::new(0, 128, 128), ACCENT);
+///     assert_eq!("#000080", colour!("Navy").to_string());
+/// ```
+///
+/// ```compile_fail
+/// # use named_colour::colour;
+///     let colour = colour!("tael");
+/// ```
+#[macro_export]
+macro_rules! colour {
+    ($name:literal) => {{
+        const COLOUR: $crate::__private::Named = match $crate::__private::named($name) {
+            ::core::option::Option::Some(colour) => colour,
+            ::core::option::Option::None => ::core::panic!(::core::concat!(
+                "unknown colour name `",
+                $name,
+                "`, expected a name such as `DarkOrchid` in any case"
+            )),
+        };
+        COLOUR
+    }};
+}
+
+/// An RGB colour from a hex code, checked when the crate is compiled
+///
+/// Accepts six digit codes such as `"#4682b4"` and the three digit shorthand
+/// such as `"#abc"`, in either case and with an optional `#`. The macro
+/// expands to a constant `RGB8`.
+///
+/// An invalid hex code fails to compile, naming the code that was rejected.
+///
+/// ## Example
+///
+/// ```
+/// # use named_colour::{hex, RGB8};
+///     const STEEL_BLUE: RGB8 = hex!("#4682b4");
+///
+///     assert_eq!(RGB8::new(70, 130, 180), STEEL_BLUE);
+///     assert_eq!(RGB8::new(170, 187, 204), hex!("#abc"));
+/// ```
+///
+/// ```compile_fail
+/// # use named_colour::hex;
+///     let colour = hex!("#4682g4");
+/// ```
+#[macro_export]
+macro_rules! hex {
+    ($hex:literal) => {{
+        const RGB: $crate::RGB8 = match $crate::__private::parse_hex($hex) {
+            ::core::option::Option::Some(rgb) => rgb,
+            ::core::option::Option::None => ::core::panic!(::core::concat!(
+                "invalid hex colour `",
+                $hex,
+                "`, expected `#RRGGBB` or `#RGB`"
+            )),
+        };
+        RGB
+    }};
+}
+
+/// Items used by the exported macros, not part of the public API
+pub mod private {
+    #[cfg(feature = "extended")]
+    pub use crate::ext::NamedColour as Named;
+    #[cfg(not(feature = "extended"))]
+    pub use crate::Basic as Named;
+
+    pub use crate::__impl_extended_colour as impl_extended_colour;
+    pub use crate::__impl_rand_distribution as impl_rand_distribution;
+    pub use crate::table::{check, hex_to_rgb, position, Index, Row};
+    pub use crate::to_hex::parse_hex;
+    #[cfg(feature = "rand")]
+    pub use rand;
+    pub use strum;
+    pub use tinyrand;
+
+    /// Find a named colour ignoring ASCII case
+    pub const fn named(name: &str) -> Option<Named> {
+        Named::from_name(name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::RGB8;
+
+    #[test]
+    fn test_colour() {
+        const TEAL: RGB8 = colour!("TEAL").to_rgb();
+
+        assert_eq!(RGB8::new(0, 128, 128), TEAL);
+        assert_eq!("#C0C0C0", colour!("silver").to_string());
+    }
+
+    #[cfg(feature = "extended")]
+    #[test]
+    fn test_extended_colour() {
+        use crate::ext::{NamedColour, Purple};
+
+        assert_eq!(
+            NamedColour::Purple(Purple::DarkOrchid),
+            colour!("darkorchid")
+        );
+    }
+
+    #[test]
+    fn test_hex() {
+        const STEEL_BLUE: RGB8 = hex!("#4682b4");
+
+        assert_eq!(RGB8::new(70, 130, 180), STEEL_BLUE);
+        assert_eq!(RGB8::new(170, 187, 204), hex!("#abc"));
+        assert_eq!(RGB8::new(255, 255, 255), hex!("FFF"));
+    }
+
+    /// The macros expand without using names from the scope they are used in
+    mod shadowed {
+        #![allow(dead_code, unused_imports, unused_macros)]
+
+        use crate::RGB8;
+
+        enum Shadow {
+            Some(u8),
+            None,
+        }
+        use Shadow::{None, Some};
+
+        macro_rules! panic {
+            ($($tokens:tt)*) => {
+                compile_error!("the prelude panic! is shadowed")
+            };
+        }
+
+        macro_rules! concat {
+            ($($tokens:tt)*) => {
+                compile_error!("the prelude concat! is shadowed")
+            };
+        }
+
+        pub(super) const TEAL: RGB8 = crate::colour!("teal").to_rgb();
+        pub(super) const STEEL_BLUE: RGB8 = crate::hex!("#4682b4");
+    }
+
+    #[test]
+    fn test_shadowed_prelude() {
+        assert_eq!(RGB8::new(0, 128, 128), shadowed::TEAL);
+        assert_eq!(RGB8::new(70, 130, 180), shadowed::STEEL_BLUE);
+    }
+}
@@ -0,0 +1,586 @@
+//! Parse and generate `LS_COLORS` strings
+//!
+//! `LS_COLORS` is the environment variable used by GNU `ls` (and generated by
+//! `dircolors`) to colour directory listings. It is a colon separated list of
+//! `key=value` entries. The key is a two letter file type code such as `di`
+//! or a glob such as `*.tar`, and the value is a list of SGR codes separated
+//! by `;`.
+//!
+//! ## Examples
+//!
+//! ### Map an existing `LS_COLORS` to named colours
+//!
+//! ```
+//! use named_colour::ext::{Blue, NamedColour};
+//! use named_colour::ls_colors::LsColors;
+//!
+//! let ls_colors = LsColors::parse("di=01;34:ln=01;36:*.tar=01;31").unwrap();
+//!
+//! let directory = ls_colors.file_type("di").unwrap();
+//! assert_eq!(Some(NamedColour::Blue(Blue::Blue)), directory.foreground_named());
+//! ```
+//!
+//! ### Generate `LS_COLORS` from named colours
+//!
+//! ```
+//! use named_colour::ext::{Blue, Red};
+//! use named_colour::ls_colors::{LsColors, LsKey, Style};
+//!
+//! let theme = LsColors::from_iter([
+//!     (LsKey::file_type("di"), Style::new().bold().with_foreground(Blue::DodgerBlue)),
+//!     (LsKey::extension("rs"), Style::new().with_foreground(Red::Coral)),
+//! ]);
+//!
+//! assert_eq!("di=01;38;2;30;144;255:*.rs=38;2;255;127;80", theme.to_string());
+//! ```
+//!
+
+use std::{fmt, str::FromStr};
+
+use rgb::Rgb;
+
+use crate::ext::NamedColour;
+
+/// The key of an `LS_COLORS` entry
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum LsKey {
+    /// A file type code such as `di` (directory) or `ln` (symbolic link)
+    FileType(String),
+    /// A file extension matched by a `*.ext` glob, stored without the `*.`
+    Extension(String),
+    /// Any other glob, stored without the leading `*`
+    Pattern(String),
+}
+
+impl LsKey {
+    /// Create a key for a file type code such as `di`
+    pub fn file_type(code: &str) -> Self {
+        Self::FileType(code.to_string())
+    }
+
+    /// Create a key for a file extension such as `tar`
+    pub fn extension(extension: &str) -> Self {
+        Self::Extension(extension.to_string())
+    }
+}
+
+impl fmt::Display for LsKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::FileType(code) => write!(f, "{code}"),
+            Self::Extension(extension) => write!(f, "*.{extension}"),
+            Self::Pattern(pattern) => write!(f, "*{pattern}"),
+        }
+    }
+}
+
+impl FromStr for LsKey {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Some(extension) = s.strip_prefix("*.") {
+            Ok(Self::Extension(extension.to_string()))
+        } else if let Some(pattern) = s.strip_prefix('*') {
+            Ok(Self::Pattern(pattern.to_string()))
+        } else if s.is_empty() {
+            Err("Invalid LS_COLORS key: empty key".to_string())
+        } else {
+            Ok(Self::FileType(s.to_string()))
+        }
+    }
+}
+
+/// A colour selected by SGR codes
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SgrColour {
+    /// One of the 16 standard terminal colours (codes 30–37 and 90–97)
+    Ansi(u8),
+    /// An entry in the 256 colour palette (`38;5;n`)
+    Indexed(u8),
+    /// A 24-bit colour (`38;2;r;g;b`)
+    Rgb(Rgb<u8>),
+}
+
+impl SgrColour {
+    /// Return the RGB value of the colour
+    ///
+    /// The standard and 256 colour palettes are resolved using the xterm
+    /// default palette.
+    pub fn to_rgb(&self) -> Rgb<u8> {
+        match self {
+            Self::Ansi(index) | Self::Indexed(index) => palette(*index),
+            Self::Rgb(rgb) => *rgb,
+        }
+    }
+
+    /// Return the named colour nearest to the colour
+    pub fn nearest_named(&self) -> NamedColour {
+        NamedColour::nearest(self.to_rgb())
+    }
+
+    fn write_codes(&self, f: &mut fmt::Formatter<'_>, base: u8, bright: u8) -> fmt::Result {
+        match self {
+            Self::Ansi(index @ 0..=7) => write!(f, "{}", base + index),
+            Self::Ansi(index @ 8..=15) => write!(f, "{}", bright + index - 8),
+            Self::Ansi(index) | Self::Indexed(index) => write!(f, "{};5;{index}", base + 8),
+            Self::Rgb(rgb) => write!(f, "{};2;{};{};{}", base + 8, rgb.r, rgb.g, rgb.b),
+        }
+    }
+}
+
+/// The xterm default palette
+fn palette(index: u8) -> Rgb<u8> {
+    const STANDARD: [(u8, u8, u8); 16] = [
+        (0, 0, 0),
+        (205, 0, 0),
+        (0, 205, 0),
+        (205, 205, 0),
+        (0, 0, 238),
+        (205, 0, 205),
+        (0, 205, 205),
+        (229, 229, 229),
+        (127, 127, 127),
+        (255, 0, 0),
+        (0, 255, 0),
+        (255, 255, 0),
+        (92, 92, 255),
+        (255, 0, 255),
+        (0, 255, 255),
+        (255, 255, 255),
+    ];
+    const LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+    match index {
+        0..=15 => {
+            let (r, g, b) = STANDARD[index as usize];
+            Rgb::new(r, g, b)
+        }
+        16..=231 => {
+            let cube = (index - 16) as usize;
+            Rgb::new(LEVELS[cube / 36], LEVELS[(cube / 6) % 6], LEVELS[cube % 6])
+        }
+        _ => {
+            let level = 8 + 10 * (index - 232);
+            Rgb::new(level, level, level)
+        }
+    }
+}
+
+/// The display style of an `LS_COLORS` entry
+///
+/// Attribute codes such as `01` (bold) are kept in the order they were given
+/// and written before the colours.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct Style {
+    attributes: Vec<u8>,
+    foreground: Option<SgrColour>,
+    background: Option<SgrColour>,
+}
+
+impl Style {
+    /// Create a style with no attributes or colours
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add an SGR attribute code such as `4` (underline)
+    pub fn with_attribute(mut self, code: u8) -> Self {
+        self.attributes.push(code);
+        self
+    }
+
+    /// Add the bold attribute
+    pub fn bold(self) -> Self {
+        self.with_attribute(1)
+    }
+
+    /// Set the foreground to a 24-bit colour
+    pub fn with_foreground(mut self, colour: impl Into<Rgb<u8>>) -> Self {
+        self.foreground = Some(SgrColour::Rgb(colour.into()));
+        self
+    }
+
+    /// Set the background to a 24-bit colour
+    pub fn with_background(mut self, colour: impl Into<Rgb<u8>>) -> Self {
+        self.background = Some(SgrColour::Rgb(colour.into()));
+        self
+    }
+
+    /// The SGR attribute codes that are not colours
+    pub fn attributes(&self) -> &[u8] {
+        &self.attributes
+    }
+
+    /// The foreground colour
+    pub fn foreground(&self) -> Option<SgrColour> {
+        self.foreground
+    }
+
+    /// The background colour
+    pub fn background(&self) -> Option<SgrColour> {
+        self.background
+    }
+
+    /// The named colour nearest to the foreground colour
+    pub fn foreground_named(&self) -> Option<NamedColour> {
+        self.foreground.map(|colour| colour.nearest_named())
+    }
+
+    /// The named colour nearest to the background colour
+    pub fn background_named(&self) -> Option<NamedColour> {
+        self.background.map(|colour| colour.nearest_named())
+    }
+}
+
+impl fmt::Display for Style {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut separator = "";
+        for code in &self.attributes {
+            write!(f, "{separator}{code:02}")?;
+            separator = ";";
+        }
+        if let Some(colour) = self.foreground {
+            write!(f, "{separator}")?;
+            colour.write_codes(f, 30, 90)?;
+            separator = ";";
+        }
+        if let Some(colour) = self.background {
+            write!(f, "{separator}")?;
+            colour.write_codes(f, 40, 100)?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for Style {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut codes = s
+            .split(';')
+            .filter(|code| !code.is_empty())
+            .map(|code| code.parse::<u8>());
+        let mut next_code = || match codes.next() {
+            Some(Ok(code)) => Ok(Some(code)),
+            Some(Err(_)) => Err(format!("Invalid SGR codes: {s}")),
+            None => Ok(None),
+        };
+
+        let mut style = Self::new();
+        while let Some(code) = next_code()? {
+            match code {
+                30..=37 => style.foreground = Some(SgrColour::Ansi(code - 30)),
+                38 => style.foreground = Some(extended_colour(&mut next_code, s)?),
+                39 => style.foreground = None,
+                40..=47 => style.background = Some(SgrColour::Ansi(code - 40)),
+                48 => style.background = Some(extended_colour(&mut next_code, s)?),
+                49 => style.background = None,
+                90..=97 => style.foreground = Some(SgrColour::Ansi(code - 90 + 8)),
+                100..=107 => style.background = Some(SgrColour::Ansi(code - 100 + 8)),
+                _ => style.attributes.push(code),
+            }
+        }
+
+        Ok(style)
+    }
+}
+
+/// Read the colour following a `38` or `48` code
+fn extended_colour(
+    next_code: &mut impl FnMut() -> Result<Option<u8>, String>,
+    s: &str,
+) -> Result<SgrColour, String> {
+    let mut required = || next_code()?.ok_or_else(|| format!("Invalid SGR codes: {s}"));
+    match required()? {
+        5 => Ok(SgrColour::Indexed(required()?)),
+        2 => Ok(SgrColour::Rgb(Rgb::new(
+            required()?,
+            required()?,
+            required()?,
+        ))),
+        _ => Err(format!("Invalid SGR codes: {s}")),
+    }
+}
+
+/// A parsed `LS_COLORS` value
+///
+/// Entries keep the order in which they were parsed or inserted. Inserting a
+/// key that is already present replaces its style.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LsColors {
+    entries: Vec<(LsKey, Style)>,
+}
+
+impl LsColors {
+    /// Create an empty `LS_COLORS` value
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Parse an `LS_COLORS` string
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use named_colour::ls_colors::{LsColors, LsKey, SgrColour};
+    ///     let ls_colors = LsColors::parse("di=01;34:*.png=38;5;208:").unwrap();
+    ///
+    ///     assert_eq!(2, ls_colors.len());
+    ///     assert_eq!(
+    ///         Some(SgrColour::Indexed(208)),
+    ///         ls_colors.extension("png").unwrap().foreground()
+    ///     );
+    /// ```
+    ///
+    pub fn parse(ls_colors: &str) -> Result<Self, String> {
+        let mut parsed = Self::new();
+        for entry in ls_colors.split(':').filter(|entry| !entry.is_empty()) {
+            let Some((key, style)) = entry.split_once('=') else {
+                return Err(format!("Invalid LS_COLORS entry: {entry}"));
+            };
+            parsed.insert(key.parse()?, style.parse()?);
+        }
+
+        Ok(parsed)
+    }
+
+    /// Insert an entry, replacing the style of an existing entry with the same key
+    pub fn insert(&mut self, key: LsKey, style: Style) {
+        match self
+            .entries
+            .iter_mut()
+            .find(|(existing, _)| *existing == key)
+        {
+            Some((_, existing)) => *existing = style,
+            None => self.entries.push((key, style)),
+        }
+    }
+
+    /// The style for a key
+    pub fn get(&self, key: &LsKey) -> Option<&Style> {
+        self.entries
+            .iter()
+            .find(|(existing, _)| existing == key)
+            .map(|(_, style)| style)
+    }
+
+    /// The style for a file type code such as `di`
+    pub fn file_type(&self, code: &str) -> Option<&Style> {
+        self.get(&LsKey::file_type(code))
+    }
+
+    /// The style for a file extension such as `tar`
+    pub fn extension(&self, extension: &str) -> Option<&Style> {
+        self.get(&LsKey::extension(extension))
+    }
+
+    /// Iterate over the entries in order
+    pub fn iter(&self) -> impl Iterator<Item = (&LsKey, &Style)> {
+        self.entries.iter().map(|(key, style)| (key, style))
+    }
+
+    /// The number of entries
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Whether there are no entries
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+impl FromIterator<(LsKey, Style)> for LsColors {
+    fn from_iter<T: IntoIterator<Item = (LsKey, Style)>>(iter: T) -> Self {
+        let mut ls_colors = Self::new();
+        for (key, style) in iter {
+            ls_colors.insert(key, style);
+        }
+        ls_colors
+    }
+}
+
+impl fmt::Display for LsColors {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut separator = "";
+        for (key, style) in &self.entries {
+            write!(f, "{separator}{key}={style}")?;
+            separator = ":";
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for LsColors {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ext::{Black, Blue, Cyan, Green, Red, White, Yellow};
+    use rstest::rstest;
+
+    const DIRCOLORS: &str = "rs=0:di=01;34:ln=01;36:mh=00:pi=40;33:so=01;35:do=01;35:\
+        bd=40;33;01:cd=40;33;01:or=40;31;01:mi=00:su=37;41:sg=30;43:ca=00:\
+        tw=30;42:ow=34;42:st=37;44:ex=01;32:*.tar=01;31:*.jpg=01;35:*~=00;90:";
+
+    #[rstest]
+    #[case("di", LsKey::FileType("di".to_string()))]
+    #[case("*.tar", LsKey::Extension("tar".to_string()))]
+    #[case("*.tar.gz", LsKey::Extension("tar.gz".to_string()))]
+    #[case("*~", LsKey::Pattern("~".to_string()))]
+    #[case("*README", LsKey::Pattern("README".to_string()))]
+    fn test_key_from_str(#[case] input: &str, #[case] expected: LsKey) {
+        assert_eq!(expected, input.parse().unwrap());
+        assert_eq!(input, expected.to_string());
+    }
+
+    #[rstest]
+    #[case("01;34", &[1], Some(SgrColour::Ansi(4)), None)]
+    #[case("40;33;01", &[1], Some(SgrColour::Ansi(3)), Some(SgrColour::Ansi(0)))]
+    #[case("00;90", &[0], Some(SgrColour::Ansi(8)), None)]
+    #[case("38;5;208", &[], Some(SgrColour::Indexed(208)), None)]
+    #[case("4;38;2;70;130;180;48;5;16", &[4], Some(SgrColour::Rgb(Rgb::new(70, 130, 180))), Some(SgrColour::Indexed(16)))]
+    #[case("34;39;103", &[], None, Some(SgrColour::Ansi(11)))]
+    #[case("", &[], None, None)]
+    fn test_style_from_str(
+        #[case] input: &str,
+        #[case] attributes: &[u8],
+        #[case] foreground: Option<SgrColour>,
+        #[case] background: Option<SgrColour>,
+    ) {
+        let style: Style = input.parse().unwrap();
+
+        assert_eq!(attributes, style.attributes());
+        assert_eq!(foreground, style.foreground());
+        assert_eq!(background, style.background());
+    }
+
+    #[rstest]
+    #[case("01;3x")]
+    #[case("38;5")]
+    #[case("38;2;1;2")]
+    #[case("38;7;1")]
+    #[case("256")]
+    fn test_style_from_str_invalid(#[case] input: &str) {
+        assert!(input.parse::<Style>().is_err());
+    }
+
+    #[rstest]
+    #[case(0, Rgb::new(0, 0, 0))]
+    #[case(4, Rgb::new(0, 0, 238))]
+    #[case(15, Rgb::new(255, 255, 255))]
+    #[case(16, Rgb::new(0, 0, 0))]
+    #[case(67, Rgb::new(95, 135, 175))]
+    #[case(208, Rgb::new(255, 135, 0))]
+    #[case(231, Rgb::new(255, 255, 255))]
+    #[case(232, Rgb::new(8, 8, 8))]
+    #[case(255, Rgb::new(238, 238, 238))]
+    fn test_palette(#[case] index: u8, #[case] expected: Rgb<u8>) {
+        assert_eq!(expected, SgrColour::Indexed(index).to_rgb());
+    }
+
+    #[rstest]
+    #[case("di", Some(NamedColour::Blue(Blue::Blue)), None)]
+    #[case("ln", Some(NamedColour::Cyan(Cyan::DarkTurquoise)), None)]
+    #[case("ex", Some(NamedColour::Green(Green::Lime)), None)]
+    #[case(
+        "pi",
+        Some(NamedColour::Yellow(Yellow::Gold)),
+        Some(NamedColour::Black(Black::Black))
+    )]
+    #[case(
+        "su",
+        Some(NamedColour::Black(Black::Gainsboro)),
+        Some(NamedColour::Red(Red::Red))
+    )]
+    #[case("mi", None, None)]
+    fn test_nearest_named_for_file_type(
+        #[case] code: &str,
+        #[case] foreground: Option<NamedColour>,
+        #[case] background: Option<NamedColour>,
+    ) {
+        let ls_colors = LsColors::parse(DIRCOLORS).unwrap();
+        let style = ls_colors.file_type(code).unwrap();
+
+        assert_eq!(foreground, style.foreground_named());
+        assert_eq!(background, style.background_named());
+    }
+
+    #[test]
+    fn test_parse_keys_by_file_type_and_extension() {
+        let ls_colors = LsColors::parse(DIRCOLORS).unwrap();
+
+        assert_eq!(21, ls_colors.len());
+        assert!(ls_colors.file_type("tw").is_some());
+        assert!(ls_colors.extension("jpg").is_some());
+        assert!(ls_colors.extension("png").is_none());
+        assert!(ls_colors.get(&LsKey::Pattern("~".to_string())).is_some());
+    }
+
+    #[test]
+    fn test_parse_later_entry_replaces_earlier() {
+        let ls_colors = LsColors::parse("di=01;34:di=32").unwrap();
+
+        assert_eq!(1, ls_colors.len());
+        assert_eq!(
+            Some(SgrColour::Ansi(2)),
+            ls_colors.file_type("di").unwrap().foreground()
+        );
+    }
+
+    #[rstest]
+    #[case("di")]
+    #[case("di=01;34:=32")]
+    #[case("di=01;34:ln=01;3x")]
+    fn test_parse_invalid(#[case] input: &str) {
+        assert!(LsColors::parse(input).is_err());
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let ls_colors = LsColors::parse(DIRCOLORS).unwrap();
+        let generated = ls_colors.to_string();
+
+        assert_eq!(ls_colors, LsColors::parse(&generated).unwrap());
+    }
+
+    #[test]
+    fn test_generate_from_named_colours() {
+        let theme = LsColors::from_iter([
+            (
+                LsKey::file_type("di"),
+                Style::new().bold().with_foreground(Blue::SteelBlue),
+            ),
+            (
+                LsKey::file_type("or"),
+                Style::new()
+                    .with_foreground(White::Snow)
+                    .with_background(Red::Crimson),
+            ),
+            (
+                LsKey::extension("rs"),
+                Style::new().with_foreground(NamedColour::from(Red::Coral)),
+            ),
+        ]);
+
+        assert_eq!(
+            "di=01;38;2;70;130;180:or=38;2;255;250;250;48;2;220;20;60:*.rs=38;2;255;127;80",
+            theme.to_string()
+        );
+
+        let parsed = LsColors::parse(&theme.to_string()).unwrap();
+        let directory = parsed.file_type("di").unwrap();
+        assert_eq!(
+            Some(NamedColour::Blue(Blue::SteelBlue)),
+            directory.foreground_named()
+        );
+        let orphan = parsed.file_type("or").unwrap();
+        assert_eq!(
+            Some(NamedColour::Red(Red::Crimson)),
+            orphan.background_named()
+        );
+    }
+}
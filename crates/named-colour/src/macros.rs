@@ -0,0 +1,396 @@
+//! Macros for reducing code duplication in colour modules
+
+/// Defines a colour enum from a table of variants and hex codes
+///
+/// Each row is `Variant = "#RRGGBB"`, optionally followed by `alias Variant`
+/// when the row is another name for the same colour. The table is the single
+/// source for:
+/// - the enum, implementing the strum `EnumCount` and `IntoEnumIterator`, and
+///   `From` converting the colour to its name as `&'static str`
+/// - `ALL` and `iter()` - every variant in declaration order
+/// - `Display` - the hex code as `#RRGGBB`
+/// - `parse()` and `FromStr` - by hex code or name, ignoring case, using
+///   an index sorted when the crate is compiled
+/// - `random()` and `random_with()` - a random variant
+/// - `const fn to_rgb()`, `to_hex_triplet()` and `name()`
+/// - `canonical()`, `aliases()`, `is_alias_of()` and `same_colour()` - the
+///   alias model, where each hex code has one canonical colour
+/// - `From` converting the colour to `Rgb<u8>`
+/// - `ExtendedColour`, with the `extended` feature
+/// - `Distribution` for `rand::distr::StandardUniform`, with the `rand` feature
+///
+/// The table is checked when the crate is compiled: hex codes must be
+/// uppercase `#RRGGBB`, names must be unique ignoring case and no longer than
+/// 32 bytes, and only an alias may share the hex code of another row.
+///
+/// ## Example
+///
+/// ```
+/// # use named_colour::define_colours;
+/// define_colours! {
+///     /// The colours of our brand
+///     pub Brand {
+///         Primary = "#123456",
+///         Secondary = "#F0A030",
+///         /// The old name of the primary colour
+///         Navy = "#123456" alias Primary,
+///     }
+/// }
+///
+///     assert_eq!("#123456", Brand::Primary.to_string());
+///     assert_eq!(Some(Brand::Secondary), Brand::parse("secondary"));
+///     assert_eq!(Some(Brand::Primary), Brand::parse("#123456"));
+///     assert_eq!(3, Brand::iter().count());
+///     assert_eq!(Brand::Secondary, Brand::ALL[1]);
+/// ```
+///
+/// The enum may also be written as `pub enum Brand { … }`.
+#[macro_export]
+macro_rules! define_colours {
+    (@alias) => {
+        ::core::option::Option::None
+    };
+    (@alias $canonical:ident) => {
+        ::core::option::Option::Some(stringify!($canonical))
+    };
+    (@count $($variant:ident)+) => {
+        <[()]>::len(&[$($crate::define_colours!(@unit $variant)),+])
+    };
+    (@unit $variant:ident) => {
+        ()
+    };
+    (
+        @define [$($krate:ident)?]
+        $(#[$meta:meta])*
+        $vis:vis $name:ident {
+            $(
+                $(#[$variant_meta:meta])*
+                $variant:ident = $hex:literal $(alias $canonical:ident)?
+            ),+ $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        #[allow(missing_docs)]
+        $vis enum $name {
+            $(
+                $(#[$variant_meta])*
+                $variant,
+            )+
+        }
+
+        const _: () = $crate::__private::check($name::TABLE);
+
+        #[allow(clippy::wrong_self_convention)]
+        impl $name {
+            /// The colour table in declaration order
+            pub(crate) const TABLE: &'static [$crate::__private::Row<Self>] = &[
+                $(
+                    $crate::__private::Row {
+                        colour: Self::$variant,
+                        name: stringify!($variant),
+                        hex: $hex,
+                        rgb: $crate::__private::hex_to_rgb($hex),
+                        alias_of: $crate::define_colours!(@alias $($canonical)?),
+                    },
+                )+
+            ];
+
+            /// Every colour in declaration order
+            pub const ALL: &'static [Self] = &[$(Self::$variant),+];
+
+            const INDEX: &'static $crate::__private::Index<
+                { $crate::define_colours!(@count $($variant)+) },
+            > = &$crate::__private::Index::new(Self::TABLE);
+
+            const fn row(&self) -> &'static $crate::__private::Row<Self> {
+                &Self::TABLE[*self as usize]
+            }
+
+            /// Iterate over every colour in declaration order
+            $(
+                ///
+                /// ## Example
+                ///
+                #[doc = concat!(
+                    "```\n",
+                    "# use ", stringify!($krate), "::", stringify!($name), ";\n",
+                    "    let names: Vec<&str> = ", stringify!($name), "::iter().map(|colour| colour.name()).collect();\n",
+                    "\n",
+                    "    assert_eq!(", stringify!($name), "::ALL.len(), names.len());\n",
+                    "```",
+                )]
+            )?
+            pub fn iter() -> ::core::iter::Copied<::core::slice::Iter<'static, Self>> {
+                Self::ALL.iter().copied()
+            }
+
+            /// Display the colour as an RGB tuple
+            ///
+            /// The value is decoded when the crate is compiled, so this can be
+            /// used in `const` and `static` items.
+            pub const fn to_rgb(&self) -> $crate::RGB8 {
+                self.row().rgb
+            }
+
+            /// Display the colour as a hex triplet with optional prefix
+            pub fn to_hex_triplet(&self, prefix: $crate::Prefix) -> ::std::string::String {
+                let rgb = self.to_rgb();
+
+                let prefix = match prefix {
+                    $crate::Prefix::Hash => "#",
+                    $crate::Prefix::None => "",
+                };
+
+                ::std::format!("{}{:02X}{:02X}{:02X}", prefix, rgb.r, rgb.g, rgb.b)
+            }
+
+            /// Return the name of the colour
+            pub fn name(&self) -> &'static str {
+                self.row().name
+            }
+
+            /// Return the colour this is an alias of, or the colour itself
+            ///
+            /// Every hex code has one canonical colour, which is the colour
+            /// `parse()` returns for the hex code.
+            pub const fn canonical(&self) -> Self {
+                match self.row().alias_of {
+                    ::core::option::Option::Some(name) => {
+                        match $crate::__private::position(Self::TABLE, name) {
+                            ::core::option::Option::Some(row) => Self::TABLE[row].colour,
+                            ::core::option::Option::None => *self,
+                        }
+                    }
+                    ::core::option::Option::None => *self,
+                }
+            }
+
+            /// Return true if the colour is an alias of another colour
+            pub const fn is_alias(&self) -> bool {
+                self.row().alias_of.is_some()
+            }
+
+            /// Return true if the colour is an alias of `other`, which is its
+            /// canonical colour
+            pub const fn is_alias_of(&self, other: &Self) -> bool {
+                self.is_alias() && self.canonical() as usize == *other as usize
+            }
+
+            /// Return true if the colours are the same colour by different
+            /// names, or the same name
+            pub const fn same_colour(&self, other: &Self) -> bool {
+                self.canonical() as usize == other.canonical() as usize
+            }
+
+            /// Iterate over the other names of the same colour in declaration order
+            $(
+                ///
+                /// ## Example
+                ///
+                #[doc = concat!(
+                    "```\n",
+                    "# use ", stringify!($krate), "::", stringify!($name), ";\n",
+                    "    let colour = ", stringify!($name), "::random();\n",
+                    "\n",
+                    "    for alias in colour.aliases() {\n",
+                    "        assert!(alias.same_colour(&colour));\n",
+                    "    }\n",
+                    "```",
+                )]
+            )?
+            pub fn aliases(&self) -> impl ::core::iter::Iterator<Item = Self> {
+                let colour = *self;
+                Self::iter().filter(move |other| *other != colour && other.same_colour(&colour))
+            }
+
+            /// Parse a colour from a hex code or name
+            ///
+            /// Hex codes may have a `#` prefix and both are matched without
+            /// regard to case. A hex code shared by several names returns the
+            /// colour that is not an alias.
+            $(
+                ///
+                /// ## Example
+                ///
+                #[doc = concat!(
+                    "```\n",
+                    "# use ", stringify!($krate), "::", stringify!($name), ";\n",
+                    "    let colour = ", stringify!($name), "::random();\n",
+                    "\n",
+                    "    assert_eq!(Some(colour), ", stringify!($name), "::parse(colour.name()));\n",
+                    "```",
+                )]
+            )?
+            pub fn parse(name: &str) -> ::core::option::Option<Self> {
+                Self::INDEX.find(name).map(|row| Self::TABLE[row].colour)
+            }
+
+            /// Find a colour by name, ignoring ASCII case, in `const` context
+            pub(crate) const fn from_name(name: &str) -> ::core::option::Option<Self> {
+                match $crate::__private::position(Self::TABLE, name) {
+                    ::core::option::Option::Some(row) => {
+                        ::core::option::Option::Some(Self::TABLE[row].colour)
+                    }
+                    ::core::option::Option::None => ::core::option::Option::None,
+                }
+            }
+
+            /// Generate a random colour
+            ///
+            /// Every variant is equally likely, using a generator seeded from
+            /// entropy.
+            $(
+                ///
+                /// ## Example
+                ///
+                #[doc = concat!(
+                    "```\n",
+                    "# use ", stringify!($krate), "::", stringify!($name), ";\n",
+                    "    let colour = ", stringify!($name), "::random();\n",
+                    "```",
+                )]
+            )?
+            pub fn random() -> Self {
+                Self::random_with(&mut $crate::random::from_entropy())
+            }
+
+            /// Generate a random colour with the given generator
+            ///
+            /// Every variant is equally likely.
+            $(
+                ///
+                /// ## Example
+                ///
+                #[doc = concat!(
+                    "```\n",
+                    "# use ", stringify!($krate), "::", stringify!($name), ";\n",
+                    "# use ", stringify!($krate), "::random::{Seeded, StdRand};\n",
+                    "    let colour = ", stringify!($name), "::random_with(&mut StdRand::seed(7));\n",
+                    "\n",
+                    "    assert_eq!(colour, ", stringify!($name), "::random_with(&mut StdRand::seed(7)));\n",
+                    "```",
+                )]
+            )?
+            pub fn random_with<R: $crate::random::Rand>(rand: &mut R) -> Self {
+                use $crate::__private::tinyrand::RandRange;
+
+                Self::ALL[rand.next_range(0..Self::ALL.len())]
+            }
+        }
+
+        impl ::std::fmt::Display for $name {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                f.write_str(self.row().hex)
+            }
+        }
+
+        impl ::core::convert::From<$name> for $crate::RGB8 {
+            fn from(colour: $name) -> Self {
+                colour.to_rgb()
+            }
+        }
+
+        impl ::core::convert::From<$name> for &'static str {
+            fn from(colour: $name) -> Self {
+                colour.name()
+            }
+        }
+
+        impl<'a> ::core::convert::From<&'a $name> for &'static str {
+            fn from(colour: &'a $name) -> Self {
+                colour.name()
+            }
+        }
+
+        impl ::std::str::FromStr for $name {
+            type Err = ::std::string::String;
+            fn from_str(s: &str) -> ::core::result::Result<Self, Self::Err> {
+                match Self::parse(s) {
+                    ::core::option::Option::Some(colour) => ::core::result::Result::Ok(colour),
+                    ::core::option::Option::None => {
+                        ::core::result::Result::Err(::std::format!("Invalid Colour: {s}"))
+                    }
+                }
+            }
+        }
+
+        impl $crate::__private::strum::EnumCount for $name {
+            const COUNT: usize = Self::TABLE.len();
+        }
+
+        impl $crate::__private::strum::IntoEnumIterator for $name {
+            type Iterator = ::core::iter::Copied<::core::slice::Iter<'static, Self>>;
+
+            fn iter() -> Self::Iterator {
+                Self::ALL.iter().copied()
+            }
+        }
+
+        $crate::__private::impl_extended_colour!($name);
+        $crate::__private::impl_rand_distribution!($name);
+    };
+    (
+        $(#[$meta:meta])*
+        $vis:vis enum $name:ident { $($rows:tt)+ }
+    ) => {
+        $crate::define_colours! { @define [] $(#[$meta])* $vis $name { $($rows)+ } }
+    };
+    (
+        $(#[$meta:meta])*
+        $vis:vis $name:ident { $($rows:tt)+ }
+    ) => {
+        $crate::define_colours! { @define [] $(#[$meta])* $vis $name { $($rows)+ } }
+    };
+}
+
+/// Defines one of the crate's colour enums with `define_colours!`
+///
+/// The generated methods get examples run as doc tests of this crate. An RGB
+/// constant named in screaming snake case is also defined for each row, in a
+/// `consts` module re-exported by [`crate::consts`].
+macro_rules! colour_table {
+    (
+        @consts $name:ident {
+            $(
+                $(#[$variant_meta:meta])*
+                $variant:ident = $hex:literal $(alias $canonical:ident)?
+            ),+ $(,)?
+        }
+    ) => {
+        /// RGB constants for every colour in the table
+        pub(crate) mod consts {
+            ::paste::paste! {
+                $(
+                    #[doc = concat!("The RGB value of `", stringify!($name), "::", stringify!($variant), "`")]
+                    pub const [<$variant:snake:upper>]: $crate::RGB8 = super::$name::$variant.to_rgb();
+                )+
+            }
+        }
+    };
+    (
+        $(#[$meta:meta])*
+        $vis:vis enum $name:ident { $($rows:tt)+ }
+    ) => {
+        $crate::define_colours! { @define [named_colour] $(#[$meta])* $vis $name { $($rows)+ } }
+        colour_table! { @consts $name { $($rows)+ } }
+    };
+}
+
+/// Implements `ExtendedColour` for a type defined by `define_colours!`
+#[cfg(feature = "extended")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_extended_colour {
+    ($name:ident) => {
+        impl $crate::ExtendedColour for $name {}
+    };
+}
+
+/// `ExtendedColour` is only available with the `extended` feature
+#[cfg(not(feature = "extended"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_extended_colour {
+    ($name:ident) => {};
+}
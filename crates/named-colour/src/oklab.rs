@@ -0,0 +1,206 @@
+//! OKLab and OKLCH colours
+//!
+//! OKLab is a perceptual colour space by Björn Ottosson where equal steps look
+//! about equally different, and OKLCH is the same space in polar form, with a
+//! chroma and a hue angle in place of `a` and `b`. Colours outside the sRGB
+//! gamut are brought into it by reducing their chroma, so converting back to
+//! RGB keeps the lightness and hue.
+//!
+//! ## Example
+//!
+//! ```
+//! use named_colour::oklab::{Oklab, Oklch};
+//! use rgb::Rgb;
+//!
+//! let red = Oklch::from(Rgb::new(255, 0, 0));
+//! assert_eq!(29, red.h.round() as i32);
+//!
+//! let rotated = Oklch::new(red.l, red.c, red.h + 180.0);
+//! assert_eq!(Rgb::new(0, 154, 172), Rgb::from(rotated));
+//! ```
+//!
+
+use rgb::Rgb;
+
+use crate::lab::{encode, linear};
+
+/// A colour in the OKLab colour space
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Oklab {
+    /// Lightness, from 0 for black to 1 for white
+    pub l: f64,
+    /// Position between green (negative) and red (positive)
+    pub a: f64,
+    /// Position between blue (negative) and yellow (positive)
+    pub b: f64,
+}
+
+/// A colour in the OKLCH colour space, the polar form of OKLab
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Oklch {
+    /// Lightness, from 0 for black to 1 for white
+    pub l: f64,
+    /// Chroma, from 0 for grey to about 0.37
+    pub c: f64,
+    /// Hue angle in degrees
+    pub h: f64,
+}
+
+impl Oklab {
+    /// Create a colour from its lightness and `a` and `b` components
+    pub const fn new(l: f64, a: f64, b: f64) -> Self {
+        Self { l, a, b }
+    }
+
+    /// Convert to linear RGB, which may be outside the range 0 to 1
+    fn to_linear(self) -> [f64; 3] {
+        let l = (self.l + 0.396_337_777_4 * self.a + 0.215_803_757_3 * self.b).powi(3);
+        let m = (self.l - 0.105_561_345_8 * self.a - 0.063_854_172_8 * self.b).powi(3);
+        let s = (self.l - 0.089_484_177_5 * self.a - 1.291_485_548 * self.b).powi(3);
+
+        [
+            4.076_741_662_1 * l - 3.307_711_591_3 * m + 0.230_969_929_2 * s,
+            -1.268_438_004_6 * l + 2.609_757_401_1 * m - 0.341_319_396_5 * s,
+            -0.004_196_086_3 * l - 0.703_418_614_7 * m + 1.707_614_701 * s,
+        ]
+    }
+}
+
+impl Oklch {
+    /// Create a colour from its lightness, chroma and hue in degrees
+    pub const fn new(l: f64, c: f64, h: f64) -> Self {
+        Self { l, c, h }
+    }
+}
+
+impl From<Rgb<u8>> for Oklab {
+    fn from(rgb: Rgb<u8>) -> Self {
+        let (r, g, b) = (linear(rgb.r), linear(rgb.g), linear(rgb.b));
+
+        let l = (0.412_221_470_8 * r + 0.536_332_536_3 * g + 0.051_445_992_9 * b).cbrt();
+        let m = (0.211_903_498_2 * r + 0.680_699_545_1 * g + 0.107_396_956_6 * b).cbrt();
+        let s = (0.088_302_461_9 * r + 0.281_718_837_6 * g + 0.629_978_700_5 * b).cbrt();
+
+        Self {
+            l: 0.210_454_255_3 * l + 0.793_617_785 * m - 0.004_072_046_8 * s,
+            a: 1.977_998_495_1 * l - 2.428_592_205 * m + 0.450_593_709_9 * s,
+            b: 0.025_904_037_1 * l + 0.782_771_766_2 * m - 0.808_675_766 * s,
+        }
+    }
+}
+
+impl From<Oklch> for Oklab {
+    fn from(lch: Oklch) -> Self {
+        let h = lch.h.to_radians();
+        Self::new(lch.l, lch.c * h.cos(), lch.c * h.sin())
+    }
+}
+
+impl From<Oklab> for Oklch {
+    fn from(lab: Oklab) -> Self {
+        let h = lab.b.atan2(lab.a).to_degrees().rem_euclid(360.0);
+        Self::new(lab.l, lab.a.hypot(lab.b), h)
+    }
+}
+
+impl From<Rgb<u8>> for Oklch {
+    fn from(rgb: Rgb<u8>) -> Self {
+        Oklab::from(rgb).into()
+    }
+}
+
+impl From<Oklab> for Rgb<u8> {
+    fn from(lab: Oklab) -> Self {
+        Oklch::from(lab).into()
+    }
+}
+
+impl From<Oklch> for Rgb<u8> {
+    fn from(lch: Oklch) -> Self {
+        const EPSILON: f64 = 0.000_1;
+        let in_gamut = |rgb: [f64; 3]| rgb.iter().all(|&c| (-EPSILON..=1.0 + EPSILON).contains(&c));
+
+        // Black and white are the only colours at the ends of the lightness
+        if lch.l <= 0.0 {
+            return Rgb::new(0, 0, 0);
+        } else if lch.l >= 1.0 {
+            return Rgb::new(255, 255, 255);
+        }
+
+        let l = lch.l;
+        let mut rgb = Oklab::from(Oklch::new(l, lch.c, lch.h)).to_linear();
+
+        if !in_gamut(rgb) {
+            // Bisect for the largest chroma inside the gamut
+            let (mut low, mut high) = (0.0, lch.c);
+            rgb = Oklab::new(l, 0.0, 0.0).to_linear();
+            for _ in 0..24 {
+                let c = (low + high) / 2.0;
+                let candidate = Oklab::from(Oklch::new(l, c, lch.h)).to_linear();
+                if in_gamut(candidate) {
+                    (low, rgb) = (c, candidate);
+                } else {
+                    high = c;
+                }
+            }
+        }
+
+        Rgb::new(encode(rgb[0]), encode(rgb[1]), encode(rgb[2]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(Rgb::new(255, 255, 255), Oklab::new(1.0, 0.0, 0.0))]
+    #[case(Rgb::new(0, 0, 0), Oklab::new(0.0, 0.0, 0.0))]
+    #[case(Rgb::new(255, 0, 0), Oklab::new(0.627_955, 0.224_863, 0.125_846))]
+    #[case(Rgb::new(0, 0, 255), Oklab::new(0.452_014, -0.032_457, -0.311_528))]
+    fn test_oklab_from_rgb(#[case] rgb: Rgb<u8>, #[case] expected: Oklab) {
+        let lab = Oklab::from(rgb);
+
+        assert!((expected.l - lab.l).abs() < 0.000_1, "{lab:?}");
+        assert!((expected.a - lab.a).abs() < 0.000_1, "{lab:?}");
+        assert!((expected.b - lab.b).abs() < 0.000_1, "{lab:?}");
+    }
+
+    #[rstest]
+    #[case(Rgb::new(0, 0, 0))]
+    #[case(Rgb::new(255, 255, 255))]
+    #[case(Rgb::new(255, 0, 0))]
+    #[case(Rgb::new(70, 130, 180))]
+    #[case(Rgb::new(1, 254, 128))]
+    fn test_round_trip(#[case] rgb: Rgb<u8>) {
+        assert_eq!(rgb, Rgb::from(Oklab::from(rgb)));
+        assert_eq!(rgb, Rgb::from(Oklch::from(rgb)));
+    }
+
+    #[test]
+    fn test_grey_has_no_chroma() {
+        let grey = Oklch::from(Rgb::new(128, 128, 128));
+
+        assert!(grey.c < 0.000_1, "{grey:?}");
+    }
+
+    #[test]
+    fn test_out_of_gamut_keeps_lightness_and_hue() {
+        let vivid = Oklch::new(0.7, 0.4, 150.0);
+        let mapped = Oklch::from(Rgb::from(vivid));
+
+        assert!((vivid.l - mapped.l).abs() < 0.01, "{mapped:?}");
+        assert!((vivid.h - mapped.h).abs() < 2.0, "{mapped:?}");
+        assert!(mapped.c < vivid.c);
+    }
+
+    #[test]
+    fn test_lightness_is_clamped() {
+        assert_eq!(
+            Rgb::new(255, 255, 255),
+            Rgb::from(Oklch::new(1.5, 0.1, 40.0))
+        );
+        assert_eq!(Rgb::new(0, 0, 0), Rgb::from(Oklch::new(-0.5, 0.0, 0.0)));
+    }
+}
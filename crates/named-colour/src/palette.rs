@@ -0,0 +1,537 @@
+//! Runtime palettes of named colours
+//!
+//! A [`Palette`] holds colours whose names are only known at runtime, such as
+//! brand colours loaded from configuration or colours imported from a palette
+//! file. Palettes can be merged with the built-in colour families so that
+//! custom colours are looked up alongside `ext::*`.
+//!
+//! ## Examples
+//!
+//! ### Build a palette
+//!
+//! ```
+//! use named_colour::palette::Palette;
+//! use rgb::Rgb;
+//!
+//! let mut palette = Palette::new();
+//! palette.insert("Brand Blue", Rgb::new(18, 52, 86));
+//! palette.insert("Brand Orange", Rgb::new(255, 128, 0));
+//! palette.add_alias("Brand Orange", "Accent");
+//!
+//! assert_eq!(Some(Rgb::new(18, 52, 86)), palette.get("brand blue"));
+//! assert_eq!(Some(Rgb::new(255, 128, 0)), palette.get("accent"));
+//! assert_eq!("Brand Orange", palette.nearest(Rgb::new(250, 120, 10)).unwrap().name());
+//! ```
+//!
+//! ### Load a palette and merge the built-in colours
+//!
+//! With the `serde` feature palettes are loaded with serde from any
+//! self-describing format, such as JSON or TOML. Colours are given as hex
+//! codes and aliases are optional.
+//!
+//! ```
+//! # #[cfg(feature = "serde")]
+//! # fn main() {
+//! use named_colour::ext::Blue;
+//! use named_colour::palette::Palette;
+//!
+//! let json = r##"{
+//!     "name": "Brand",
+//!     "colours": [{ "name": "Primary", "hex": "#123456", "aliases": ["Brand Blue"] }]
+//! }"##;
+//!
+//! let mut palette: Palette = serde_json::from_str(json).unwrap();
+//! palette.merge_family::<Blue>();
+//!
+//! assert_eq!("#123456", palette.find("brand blue").unwrap().to_string());
+//! assert_eq!("SteelBlue", palette.find("#4682b4").unwrap().name());
+//! # }
+//! # #[cfg(not(feature = "serde"))]
+//! # fn main() {}
+//! ```
+//!
+
+use std::fmt;
+
+use rgb::Rgb;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use strum::IntoEnumIterator;
+
+use crate::{
+    ext::{distance, NamedColour},
+    to_hex::parse_hex,
+    ToHex,
+};
+
+/// A named colour in a [`Palette`]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PaletteEntry {
+    name: String,
+    #[cfg_attr(feature = "serde", serde(rename = "hex", with = "hex_colour"))]
+    colour: Rgb<u8>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
+    aliases: Vec<String>,
+}
+
+impl PaletteEntry {
+    /// The name of the colour
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The RGB value of the colour
+    pub fn colour(&self) -> Rgb<u8> {
+        self.colour
+    }
+
+    /// The alternative names of the colour
+    pub fn aliases(&self) -> impl Iterator<Item = &str> {
+        self.aliases.iter().map(String::as_str)
+    }
+
+    fn is_named(&self, name: &str) -> bool {
+        self.name.eq_ignore_ascii_case(name)
+    }
+
+    fn is_aliased(&self, name: &str) -> bool {
+        self.aliases
+            .iter()
+            .any(|alias| alias.eq_ignore_ascii_case(name))
+    }
+}
+
+impl fmt::Display for PaletteEntry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.colour.as_hex())
+    }
+}
+
+/// A palette of named colours defined at runtime
+///
+/// Entries keep the order in which they were inserted. Names and aliases are
+/// matched without regard to ASCII case, and inserting a name that is already
+/// present replaces its colour. Palettes loaded from files or with serde keep
+/// every entry, even where a name is repeated.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Palette {
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    name: Option<String>,
+    #[cfg_attr(feature = "serde", serde(rename = "colours", default))]
+    entries: Vec<PaletteEntry>,
+}
+
+impl Palette {
+    /// Create an empty palette
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create an empty palette with a name
+    pub fn with_name(name: &str) -> Self {
+        Self {
+            name: Some(name.to_string()),
+            entries: Vec::new(),
+        }
+    }
+
+    /// Create a palette from a set of named colours
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use named_colour::ext::{Blue, Red};
+    /// # use named_colour::palette::Palette;
+    ///     let palette = Palette::from_named([Blue::SteelBlue.into(), Red::Crimson.into()]);
+    ///
+    ///     assert_eq!(Some(Red::Crimson.to_rgb()), palette.get("crimson"));
+    /// ```
+    pub fn from_named(colours: impl IntoIterator<Item = NamedColour>) -> Self {
+        let mut palette = Self::new();
+        palette.extend(colours);
+        palette
+    }
+
+    /// Create a palette from every colour in a family such as `ext::Blue`
+    pub fn from_family<T>() -> Self
+    where
+        T: IntoEnumIterator + Into<NamedColour>,
+    {
+        Self::from_named(T::iter().map(Into::into))
+    }
+
+    /// The name of the palette
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// Set the name of the palette
+    pub fn set_name(&mut self, name: &str) {
+        self.name = Some(name.to_string());
+    }
+
+    /// Insert a named colour, replacing the colour of an existing entry with the same name
+    pub fn insert(&mut self, name: &str, colour: Rgb<u8>) {
+        match self.entries.iter_mut().find(|entry| entry.is_named(name)) {
+            Some(entry) => entry.colour = colour,
+            None => self.push(name, colour),
+        }
+    }
+
+    /// Append a named colour, keeping any existing entry with the same name
+    ///
+    /// Lookups by name find the first entry with the name, but every entry is
+    /// kept in iteration order. This suits palette files, which may repeat a
+    /// name such as `Untitled`.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use named_colour::palette::Palette;
+    /// # use rgb::Rgb;
+    ///     let mut palette = Palette::new();
+    ///     palette.push("Untitled", Rgb::new(18, 52, 86));
+    ///     palette.push("Untitled", Rgb::new(255, 128, 0));
+    ///
+    ///     assert_eq!(2, palette.len());
+    ///     assert_eq!(Some(Rgb::new(18, 52, 86)), palette.get("untitled"));
+    /// ```
+    pub fn push(&mut self, name: &str, colour: Rgb<u8>) {
+        self.entries.push(PaletteEntry {
+            name: name.to_string(),
+            colour,
+            aliases: Vec::new(),
+        });
+    }
+
+    /// Add an alternative name to the entry with a name
+    ///
+    /// Returns `false` if there is no entry with the name.
+    pub fn add_alias(&mut self, name: &str, alias: &str) -> bool {
+        match self.entries.iter_mut().find(|entry| entry.is_named(name)) {
+            Some(entry) => {
+                if !entry.is_named(alias) && !entry.is_aliased(alias) {
+                    entry.aliases.push(alias.to_string());
+                }
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// The colour with a name or alias
+    pub fn get(&self, name: &str) -> Option<Rgb<u8>> {
+        self.entry(name).map(PaletteEntry::colour)
+    }
+
+    /// The entry with a name or alias
+    ///
+    /// Names take precedence over aliases.
+    pub fn entry(&self, name: &str) -> Option<&PaletteEntry> {
+        self.entries
+            .iter()
+            .find(|entry| entry.is_named(name))
+            .or_else(|| self.entries.iter().find(|entry| entry.is_aliased(name)))
+    }
+
+    /// The first entry with an RGB value
+    pub fn entry_for_colour(&self, colour: Rgb<u8>) -> Option<&PaletteEntry> {
+        self.entries.iter().find(|entry| entry.colour == colour)
+    }
+
+    /// The entry with a name, alias or hex code
+    ///
+    /// Names and aliases are tried first, then the input is read as a hex code
+    /// with an optional `#` prefix.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use named_colour::palette::Palette;
+    /// # use rgb::Rgb;
+    ///     let mut palette = Palette::new();
+    ///     palette.insert("Primary", Rgb::new(18, 52, 86));
+    ///
+    ///     assert_eq!("Primary", palette.find("#123456").unwrap().name());
+    ///     assert_eq!("Primary", palette.find("primary").unwrap().name());
+    /// ```
+    pub fn find(&self, name_or_hex: &str) -> Option<&PaletteEntry> {
+        self.entry(name_or_hex).or_else(|| {
+            let colour = parse_hex(name_or_hex)?;
+            self.entry_for_colour(colour)
+        })
+    }
+
+    /// The entry nearest to an RGB colour
+    ///
+    /// Distance is measured as the squared euclidean distance between the red,
+    /// green and blue components. Where several entries are equally near, the
+    /// first inserted is returned. Returns `None` if the palette is empty.
+    pub fn nearest(&self, colour: Rgb<u8>) -> Option<&PaletteEntry> {
+        self.entries
+            .iter()
+            .min_by_key(|entry| distance(colour, entry.colour))
+    }
+
+    /// Merge the entries of another palette
+    ///
+    /// Entries of the other palette replace the colours of entries with the
+    /// same name, and their aliases are added.
+    pub fn merge(&mut self, other: &Palette) {
+        for entry in &other.entries {
+            self.insert_entry(entry.clone());
+        }
+    }
+
+    /// Merge every colour in a family such as `ext::Blue`
+    pub fn merge_family<T>(&mut self)
+    where
+        T: IntoEnumIterator + Into<NamedColour>,
+    {
+        self.extend(T::iter().map(Into::into));
+    }
+
+    /// Iterate over the entries in order
+    pub fn iter(&self) -> impl Iterator<Item = &PaletteEntry> {
+        self.entries.iter()
+    }
+
+    /// The number of entries
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Whether there are no entries
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    fn insert_entry(&mut self, entry: PaletteEntry) {
+        self.insert(&entry.name, entry.colour);
+        for alias in &entry.aliases {
+            self.add_alias(&entry.name, alias);
+        }
+    }
+}
+
+impl Extend<NamedColour> for Palette {
+    fn extend<T: IntoIterator<Item = NamedColour>>(&mut self, iter: T) {
+        for colour in iter {
+            self.insert(colour.name(), colour.to_rgb());
+        }
+    }
+}
+
+impl<'a> IntoIterator for &'a Palette {
+    type Item = &'a PaletteEntry;
+    type IntoIter = std::slice::Iter<'a, PaletteEntry>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.entries.iter()
+    }
+}
+
+/// Serialise colours as hex codes
+#[cfg(feature = "serde")]
+mod hex_colour {
+    use rgb::Rgb;
+    use serde::{de::Error, Deserialize, Deserializer, Serializer};
+
+    use crate::{to_hex::parse_hex, ToHex};
+
+    pub(super) fn serialize<S: Serializer>(
+        colour: &Rgb<u8>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&colour.as_hex())
+    }
+
+    pub(super) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Rgb<u8>, D::Error> {
+        let hex = String::deserialize(deserializer)?;
+        parse_hex(&hex).ok_or_else(|| D::Error::custom(format!("Invalid hex colour: {hex}")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ext::{Blue, Brown, Red};
+    use rstest::rstest;
+
+    fn brand() -> Palette {
+        let mut palette = Palette::with_name("Brand");
+        palette.insert("Primary", Rgb::new(18, 52, 86));
+        palette.insert("Secondary", Rgb::new(255, 128, 0));
+        palette.insert("Background", Rgb::new(250, 250, 250));
+        palette.add_alias("Primary", "Brand Blue");
+        palette.add_alias("Secondary", "Accent");
+        palette
+    }
+
+    #[rstest]
+    #[case("Primary", Some(Rgb::new(18, 52, 86)))]
+    #[case("secondary", Some(Rgb::new(255, 128, 0)))]
+    #[case("BACKGROUND", Some(Rgb::new(250, 250, 250)))]
+    #[case("brand blue", Some(Rgb::new(18, 52, 86)))]
+    #[case("Accent", Some(Rgb::new(255, 128, 0)))]
+    #[case("Tertiary", None)]
+    #[case("#123456", None)]
+    fn test_get(#[case] name: &str, #[case] expected: Option<Rgb<u8>>) {
+        assert_eq!(expected, brand().get(name));
+    }
+
+    #[rstest]
+    #[case("Primary", Some("Primary"))]
+    #[case("accent", Some("Secondary"))]
+    #[case("#123456", Some("Primary"))]
+    #[case("ff8000", Some("Secondary"))]
+    #[case("#FAFAFA", Some("Background"))]
+    #[case("#FAFAFB", None)]
+    #[case("Tertiary", None)]
+    fn test_find(#[case] input: &str, #[case] expected: Option<&str>) {
+        assert_eq!(expected, brand().find(input).map(PaletteEntry::name));
+    }
+
+    #[test]
+    fn test_name_takes_precedence_over_alias() {
+        let mut palette = brand();
+        palette.insert("Accent", Rgb::new(1, 2, 3));
+
+        assert_eq!(Some(Rgb::new(1, 2, 3)), palette.get("accent"));
+    }
+
+    #[rstest]
+    #[case(Rgb::new(0, 0, 0), "Primary")]
+    #[case(Rgb::new(200, 100, 0), "Secondary")]
+    #[case(Rgb::new(255, 255, 255), "Background")]
+    fn test_nearest(#[case] colour: Rgb<u8>, #[case] expected: &str) {
+        assert_eq!(expected, brand().nearest(colour).unwrap().name());
+    }
+
+    #[test]
+    fn test_nearest_empty() {
+        assert!(Palette::new().nearest(Rgb::new(0, 0, 0)).is_none());
+    }
+
+    #[test]
+    fn test_from_family() {
+        let palette = Palette::from_family::<Brown>();
+
+        assert_eq!(8, palette.len());
+        assert_eq!(Some(Rgb::new(210, 180, 140)), palette.get("tan"));
+    }
+
+    #[test]
+    fn test_insert_replaces_existing_name() {
+        let mut palette = brand();
+        palette.insert("PRIMARY", Rgb::new(1, 2, 3));
+
+        assert_eq!(3, palette.len());
+        assert_eq!(Some(Rgb::new(1, 2, 3)), palette.get("Primary"));
+        assert_eq!(Some(Rgb::new(1, 2, 3)), palette.get("Brand Blue"));
+        assert_eq!("Primary", palette.iter().next().unwrap().name());
+    }
+
+    #[test]
+    fn test_add_alias() {
+        let mut palette = brand();
+
+        assert!(palette.add_alias("primary", "Navy Ink"));
+        assert!(palette.add_alias("primary", "brand blue"));
+        assert!(!palette.add_alias("Tertiary", "Other"));
+
+        let aliases: Vec<_> = palette.entry("Primary").unwrap().aliases().collect();
+        assert_eq!(vec!["Brand Blue", "Navy Ink"], aliases);
+    }
+
+    #[test]
+    fn test_merge() {
+        let mut palette = Palette::from_named([Blue::Navy.into(), Red::Crimson.into()]);
+        let mut overrides = Palette::new();
+        overrides.insert("Navy", Rgb::new(0, 0, 100));
+        overrides.insert("Ink", Rgb::new(20, 20, 40));
+        overrides.add_alias("Ink", "Text");
+
+        palette.merge(&overrides);
+
+        assert_eq!(3, palette.len());
+        assert_eq!(Some(Rgb::new(0, 0, 100)), palette.get("navy"));
+        assert_eq!(Some(Red::Crimson.to_rgb()), palette.get("crimson"));
+        assert_eq!(Some(Rgb::new(20, 20, 40)), palette.get("text"));
+    }
+
+    #[test]
+    fn test_merge_family() {
+        let mut palette = brand();
+        palette.merge_family::<Blue>();
+
+        assert_eq!(3 + Blue::iter().count(), palette.len());
+        assert_eq!(
+            "Primary",
+            palette.nearest(Rgb::new(18, 52, 86)).unwrap().name()
+        );
+        assert_eq!(
+            "MidnightBlue",
+            palette.nearest(Rgb::new(25, 25, 110)).unwrap().name()
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_json_round_trip() {
+        let json = serde_json::to_string(&brand()).unwrap();
+
+        assert_eq!(
+            r##"{"name":"Brand","colours":[{"name":"Primary","hex":"#123456","aliases":["Brand Blue"]},{"name":"Secondary","hex":"#FF8000","aliases":["Accent"]},{"name":"Background","hex":"#FAFAFA"}]}"##,
+            json
+        );
+        assert_eq!(brand(), serde_json::from_str(&json).unwrap());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_load_keeps_repeated_names() {
+        let json = r##"{"colours":[
+            {"name":"Primary","hex":"#123456"},
+            {"name":"primary","hex":"abc","aliases":["Brand"]}
+        ]}"##;
+
+        let palette: Palette = serde_json::from_str(json).unwrap();
+
+        assert_eq!(None, palette.name());
+        assert_eq!(2, palette.len());
+        assert_eq!(Some(Rgb::new(18, 52, 86)), palette.get("primary"));
+        assert_eq!(Some(Rgb::new(170, 187, 204)), palette.get("brand"));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_json_round_trip_repeated_names() {
+        let mut palette = Palette::with_name("Imported");
+        palette.push("Untitled", Rgb::new(255, 0, 0));
+        palette.push("Untitled", Rgb::new(0, 0, 255));
+
+        let json = serde_json::to_string(&palette).unwrap();
+
+        assert_eq!(palette, serde_json::from_str(&json).unwrap());
+    }
+
+    #[cfg(feature = "serde")]
+    #[rstest]
+    #[case(r##"{"colours":[{"name":"Primary","hex":"#12345"}]}"##)]
+    #[case(r##"{"colours":[{"name":"Primary"}]}"##)]
+    #[case(r##"{"colours":[{"hex":"#123456"}]}"##)]
+    fn test_load_invalid(#[case] json: &str) {
+        assert!(serde_json::from_str::<Palette>(json).is_err());
+    }
+}
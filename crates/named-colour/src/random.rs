@@ -0,0 +1,401 @@
+//! Random number generators for choosing random colours
+//!
+//! Every colour enum has `random()`, which uses a generator seeded from
+//! entropy, and `random_with()`, which takes any [`Rand`] so that the choice
+//! can be repeated with a seeded generator.
+//!
+//! With the `extended` feature, `RandomColours` picks named colours that
+//! meet constraints, such as a set of colours that are easy to tell apart.
+//!
+//! With the `rand` feature each colour enum can also be sampled from
+//! `rand::distr::StandardUniform`, so `rng.random::<Traffic>()` works with any
+//! `rand::Rng`.
+//!
+//! ## Example
+//!
+//! ```
+//! use named_colour::define_colours;
+//! use named_colour::random::{Seeded, StdRand};
+//!
+//! define_colours! {
+//!     Traffic {
+//!         Red = "#FF0000",
+//!         Amber = "#FFBF00",
+//!         Green = "#00FF00",
+//!     }
+//! }
+//!
+//! let first = Traffic::random_with(&mut StdRand::seed(42));
+//! let second = Traffic::random_with(&mut StdRand::seed(42));
+//!
+//! assert_eq!(first, second);
+//! ```
+//!
+
+use std::{
+    collections::hash_map::RandomState,
+    hash::{BuildHasher, Hasher},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+pub use tinyrand::{Rand, Seeded, StdRand};
+
+#[cfg(feature = "extended")]
+use rgb::Rgb;
+#[cfg(feature = "extended")]
+use tinyrand::RandRange;
+
+#[cfg(feature = "extended")]
+use crate::{
+    contrast::contrast_ratio,
+    ext::{all_named_colours, Family, NamedColour},
+    lab::Lab,
+};
+
+/// Create a generator seeded from entropy
+///
+/// The seed mixes the randomly keyed hasher of the standard library with the
+/// system time, so each generator gives a different sequence.
+pub fn from_entropy() -> StdRand {
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_nanos())
+        .unwrap_or_default();
+
+    let mut hasher = RandomState::new().build_hasher();
+    hasher.write_u128(nanos);
+    StdRand::seed(hasher.finish())
+}
+
+/// Picks random named colours that meet constraints
+///
+/// Colours are drawn from the extended set, or from the chosen families, and
+/// must meet every constraint given. Aliases are never picked, so each colour
+/// picked has a different RGB value.
+///
+/// Colours are light or dark by their CIELAB lightness, above or below 50,
+/// and told apart by the CIEDE2000 difference, see [`lab`](crate::lab).
+///
+/// ## Example
+///
+/// ```
+/// use named_colour::ext::{Family, White};
+/// use named_colour::random::RandomColours;
+///
+/// // Five series colours that are easy to tell apart on a white chart
+/// let series = RandomColours::new()
+///     .min_delta_e(15.0)
+///     .min_contrast(White::White, 3.0)
+///     .pick(5);
+/// assert_eq!(5, series.len());
+///
+/// // A light avatar background from the blues and greens
+/// let avatar = RandomColours::new()
+///     .families([Family::Blue, Family::Green])
+///     .light()
+///     .random()
+///     .unwrap();
+/// assert!(matches!(avatar.family(), Family::Blue | Family::Green));
+/// ```
+#[cfg(feature = "extended")]
+#[derive(Debug, Clone, Default)]
+pub struct RandomColours {
+    families: Vec<Family>,
+    light: Option<bool>,
+    min_contrast: Option<(Rgb<u8>, f64)>,
+    min_delta_e: f64,
+}
+
+#[cfg(feature = "extended")]
+impl RandomColours {
+    /// Pick from every colour in the extended set
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Only pick colours from the given families
+    pub fn families(mut self, families: impl IntoIterator<Item = Family>) -> Self {
+        self.families = families.into_iter().collect();
+        self
+    }
+
+    /// Only pick light colours, with a CIELAB lightness of 50 or more
+    pub fn light(mut self) -> Self {
+        self.light = Some(true);
+        self
+    }
+
+    /// Only pick dark colours, with a CIELAB lightness below 50
+    pub fn dark(mut self) -> Self {
+        self.light = Some(false);
+        self
+    }
+
+    /// Only pick colours with at least the WCAG 2 contrast ratio against the
+    /// background
+    pub fn min_contrast(mut self, background: impl Into<Rgb<u8>>, ratio: f64) -> Self {
+        self.min_contrast = Some((background.into(), ratio));
+        self
+    }
+
+    /// Only pick colours at least this CIEDE2000 difference from each other
+    pub fn min_delta_e(mut self, delta_e: f64) -> Self {
+        self.min_delta_e = delta_e;
+        self
+    }
+
+    /// Iterate over the colours that may be picked, in family order
+    pub fn candidates(&self) -> impl Iterator<Item = NamedColour> + '_ {
+        all_named_colours().filter(move |colour| self.allows(colour))
+    }
+
+    /// Pick a random colour, or `None` if no colour meets the constraints
+    pub fn random(&self) -> Option<NamedColour> {
+        self.random_with(&mut from_entropy())
+    }
+
+    /// Pick a random colour with the given generator
+    pub fn random_with<R: Rand>(&self, rand: &mut R) -> Option<NamedColour> {
+        self.pick_with(rand, 1).pop()
+    }
+
+    /// Pick up to `count` random colours
+    ///
+    /// Fewer colours are returned when too few meet the constraints. The
+    /// colours are chosen one at a time, so a set meeting `min_delta_e` may be
+    /// missed when the constraints leave little room.
+    pub fn pick(&self, count: usize) -> Vec<NamedColour> {
+        self.pick_with(&mut from_entropy(), count)
+    }
+
+    /// Pick up to `count` random colours with the given generator
+    pub fn pick_with<R: Rand>(&self, rand: &mut R, count: usize) -> Vec<NamedColour> {
+        let mut candidates: Vec<(NamedColour, Lab)> = self
+            .candidates()
+            .map(|colour| (colour, Lab::from(colour.to_rgb())))
+            .collect();
+        let mut picked: Vec<(NamedColour, Lab)> = Vec::with_capacity(count);
+
+        for i in 0..candidates.len() {
+            if picked.len() == count {
+                break;
+            }
+            let j = rand.next_range(i..candidates.len());
+            candidates.swap(i, j);
+
+            let (colour, lab) = candidates[i];
+            let distinct = picked.iter().all(|(other, other_lab)| {
+                other.to_rgb() != colour.to_rgb() && lab.delta_e(other_lab) >= self.min_delta_e
+            });
+            if distinct {
+                picked.push((colour, lab));
+            }
+        }
+
+        picked.into_iter().map(|(colour, _)| colour).collect()
+    }
+
+    fn allows(&self, colour: &NamedColour) -> bool {
+        let rgb = colour.to_rgb();
+
+        !colour.is_alias()
+            && (self.families.is_empty() || self.families.contains(&colour.family()))
+            && self
+                .light
+                .map_or(true, |light| (Lab::from(rgb).l >= 50.0) == light)
+            && self.min_contrast.map_or(true, |(background, ratio)| {
+                contrast_ratio(rgb, background) >= ratio
+            })
+    }
+}
+
+/// Implements `Distribution` for `StandardUniform` for a type defined by
+/// `define_colours!`
+#[cfg(feature = "rand")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_rand_distribution {
+    ($name:ident) => {
+        impl $crate::__private::rand::distr::Distribution<$name>
+            for $crate::__private::rand::distr::StandardUniform
+        {
+            fn sample<R: $crate::__private::rand::Rng + ?Sized>(&self, rng: &mut R) -> $name {
+                $name::ALL[rng.random_range(0..$name::ALL.len())]
+            }
+        }
+    };
+}
+
+/// `rand` support is only available with the `rand` feature
+#[cfg(not(feature = "rand"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_rand_distribution {
+    ($name:ident) => {};
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_entropy_is_not_repeated() {
+        let first: Vec<u64> = (0..4).map(|_| from_entropy().next_u64()).collect();
+
+        assert!(first.windows(2).any(|pair| pair[0] != pair[1]));
+    }
+
+    define_colours! {
+        Traffic {
+            Red = "#FF0000",
+            Amber = "#FFBF00",
+            Green = "#00FF00",
+        }
+    }
+
+    #[test]
+    fn test_random_is_not_repeated() {
+        let colours: Vec<Traffic> = (0..32).map(|_| Traffic::random()).collect();
+
+        assert!(colours.windows(2).any(|pair| pair[0] != pair[1]));
+    }
+
+    #[test]
+    fn test_random_with_seed_is_repeated() {
+        let first: Vec<Traffic> = (0..8)
+            .map(|seed| Traffic::random_with(&mut StdRand::seed(seed)))
+            .collect();
+        let second: Vec<Traffic> = (0..8)
+            .map(|seed| Traffic::random_with(&mut StdRand::seed(seed)))
+            .collect();
+
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn test_random_with_is_uniform() {
+        let mut rand = StdRand::seed(3);
+        let mut counts = [0; 3];
+        for _ in 0..3000 {
+            counts[Traffic::random_with(&mut rand) as usize] += 1;
+        }
+
+        assert!(counts.iter().all(|&count| (900..1100).contains(&count)));
+    }
+
+    #[cfg(feature = "extended")]
+    mod constrained {
+        use super::*;
+        use crate::{
+            ext::{Black, White},
+            lab::delta_e,
+            ToHex,
+        };
+
+        #[test]
+        fn test_pick_distinct() {
+            let colours = RandomColours::new()
+                .min_delta_e(20.0)
+                .pick_with(&mut StdRand::seed(5), 8);
+
+            assert_eq!(8, colours.len());
+            for (i, a) in colours.iter().enumerate() {
+                for b in &colours[i + 1..] {
+                    assert!(delta_e(*a, *b) >= 20.0, "{a:?} {b:?}");
+                }
+            }
+        }
+
+        #[test]
+        fn test_pick_never_repeats_a_colour() {
+            let colours = RandomColours::new()
+                .families([Family::Green, Family::Yellow])
+                .pick_with(&mut StdRand::seed(9), 1000);
+            let mut rgbs: Vec<_> = colours
+                .iter()
+                .map(|colour| colour.to_rgb().as_hex())
+                .collect();
+            rgbs.sort();
+            rgbs.dedup();
+
+            assert_eq!(colours.len(), rgbs.len());
+            assert!(colours.iter().all(|colour| !colour.is_alias()));
+        }
+
+        #[test]
+        fn test_light_and_dark() {
+            let light = RandomColours::new().light();
+            let dark = RandomColours::new().dark();
+
+            assert!(light.candidates().all(|c| Lab::from(c.to_rgb()).l >= 50.0));
+            assert!(dark.candidates().all(|c| Lab::from(c.to_rgb()).l < 50.0));
+            assert_eq!(
+                all_named_colours().filter(|c| !c.is_alias()).count(),
+                light.candidates().count() + dark.candidates().count()
+            );
+        }
+
+        #[test]
+        fn test_min_contrast() {
+            let colours = RandomColours::new().min_contrast(Black::Black, 7.0);
+
+            assert!(colours
+                .candidates()
+                .all(|c| contrast_ratio(c, Black::Black) >= 7.0));
+            assert!(colours
+                .candidates()
+                .any(|c| c == NamedColour::White(White::White)));
+        }
+
+        #[test]
+        fn test_families() {
+            let colours = RandomColours::new().families([Family::Red]);
+
+            assert!(colours.candidates().all(|c| c.family() == Family::Red));
+            assert_eq!(
+                colours.random_with(&mut StdRand::seed(2)),
+                colours.random_with(&mut StdRand::seed(2))
+            );
+        }
+
+        #[test]
+        fn test_impossible_constraints() {
+            let colours = RandomColours::new().families([Family::White]).dark();
+
+            assert_eq!(None, colours.random());
+            assert!(colours.pick(3).is_empty());
+        }
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_rand_distribution() {
+        use rand::Rng;
+
+        /// SplitMix64, as a small `rand` generator for the test
+        struct SplitMix(u64);
+
+        impl rand::RngCore for SplitMix {
+            fn next_u32(&mut self) -> u32 {
+                self.next_u64() as u32
+            }
+
+            fn next_u64(&mut self) -> u64 {
+                self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+                let mut z = self.0;
+                z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+                z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+                z ^ (z >> 31)
+            }
+
+            fn fill_bytes(&mut self, dst: &mut [u8]) {
+                rand::rand_core::impls::fill_bytes_via_next(self, dst)
+            }
+        }
+
+        let mut rng = SplitMix(0);
+        let colours: Vec<Traffic> = (0..32).map(|_| rng.random()).collect();
+
+        assert!(Traffic::ALL.iter().all(|colour| colours.contains(colour)));
+    }
+}
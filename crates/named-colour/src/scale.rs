@@ -0,0 +1,252 @@
+//! Tint, shade and tone scales
+//!
+//! A tint mixes a colour with white, a shade with black and a tone with mid
+//! grey. The colours are mixed in a chosen colour space: sRGB mixes the stored
+//! channel values, linear RGB mixes light as a screen would, and OKLab mixes
+//! perceptually, giving the most even steps. Every function accepts named
+//! colours as well as `Rgb<u8>`.
+//!
+//! ## Example
+//!
+//! ```
+//! use named_colour::scale::{shades, tints, Space};
+//! use rgb::Rgb;
+//!
+//! let teal = Rgb::new(0, 128, 128);
+//!
+//! let tints = tints(teal, 4, Space::Srgb);
+//! assert_eq!(
+//!     vec![
+//!         Rgb::new(0, 128, 128),
+//!         Rgb::new(64, 160, 160),
+//!         Rgb::new(128, 192, 192),
+//!         Rgb::new(191, 223, 223),
+//!     ],
+//!     tints
+//! );
+//!
+//! let shades = shades(teal, 2, Space::Srgb);
+//! assert_eq!(vec![Rgb::new(0, 128, 128), Rgb::new(0, 64, 64)], shades);
+//! ```
+//!
+
+use rgb::Rgb;
+
+use crate::lab::{encode, linear};
+use crate::oklab::{Oklab, Oklch};
+
+const WHITE: Rgb<u8> = Rgb::new(255, 255, 255);
+const BLACK: Rgb<u8> = Rgb::new(0, 0, 0);
+const GREY: Rgb<u8> = Rgb::new(128, 128, 128);
+
+/// The colour space in which colours are mixed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Space {
+    /// The gamma encoded sRGB channels
+    Srgb,
+    /// Linear light RGB
+    Linear,
+    /// The perceptual OKLab space
+    #[default]
+    Oklab,
+}
+
+/// Mix two colours, from the first colour at 0 to the second at 1
+///
+/// The amount is clamped between 0 and 1.
+pub fn mix(
+    first: impl Into<Rgb<u8>>,
+    second: impl Into<Rgb<u8>>,
+    amount: f64,
+    space: Space,
+) -> Rgb<u8> {
+    let (first, second) = (first.into(), second.into());
+    let t = amount.clamp(0.0, 1.0);
+    let lerp = |a: f64, b: f64| a + (b - a) * t;
+
+    match space {
+        Space::Srgb => {
+            let channel = |a: u8, b: u8| lerp(a as f64, b as f64).round() as u8;
+            Rgb::new(
+                channel(first.r, second.r),
+                channel(first.g, second.g),
+                channel(first.b, second.b),
+            )
+        }
+        Space::Linear => {
+            let channel = |a: u8, b: u8| encode(lerp(linear(a), linear(b)));
+            Rgb::new(
+                channel(first.r, second.r),
+                channel(first.g, second.g),
+                channel(first.b, second.b),
+            )
+        }
+        Space::Oklab => {
+            let (a, b) = (Oklab::from(first), Oklab::from(second));
+            Oklab::new(lerp(a.l, b.l), lerp(a.a, b.a), lerp(a.b, b.b)).into()
+        }
+    }
+}
+
+/// A scale of tints of a colour, mixed towards white
+///
+/// The scale has `steps` colours, starting with the colour itself and ending
+/// one step short of white.
+pub fn tints(colour: impl Into<Rgb<u8>>, steps: usize, space: Space) -> Vec<Rgb<u8>> {
+    scale(colour.into(), WHITE, steps, space)
+}
+
+/// A scale of shades of a colour, mixed towards black
+///
+/// The scale has `steps` colours, starting with the colour itself and ending
+/// one step short of black.
+pub fn shades(colour: impl Into<Rgb<u8>>, steps: usize, space: Space) -> Vec<Rgb<u8>> {
+    scale(colour.into(), BLACK, steps, space)
+}
+
+/// A scale of tones of a colour, mixed towards mid grey
+///
+/// The scale has `steps` colours, starting with the colour itself and ending
+/// one step short of grey.
+pub fn tones(colour: impl Into<Rgb<u8>>, steps: usize, space: Space) -> Vec<Rgb<u8>> {
+    scale(colour.into(), GREY, steps, space)
+}
+
+fn scale(colour: Rgb<u8>, target: Rgb<u8>, steps: usize, space: Space) -> Vec<Rgb<u8>> {
+    (0..steps)
+        .map(|step| mix(colour, target, step as f64 / steps as f64, space))
+        .collect()
+}
+
+/// Lighten a colour, moving its OKLCH lightness towards white by a fraction
+/// from 0 to 1
+pub fn lighten(colour: impl Into<Rgb<u8>>, amount: f64) -> Rgb<u8> {
+    adjust(colour.into(), |lch| {
+        lch.l += (1.0 - lch.l) * amount.clamp(0.0, 1.0)
+    })
+}
+
+/// Darken a colour, moving its OKLCH lightness towards black by a fraction
+/// from 0 to 1
+pub fn darken(colour: impl Into<Rgb<u8>>, amount: f64) -> Rgb<u8> {
+    adjust(colour.into(), |lch| lch.l *= 1.0 - amount.clamp(0.0, 1.0))
+}
+
+/// Saturate a colour, increasing its OKLCH chroma by a fraction
+///
+/// An amount of 1 doubles the chroma, within the limits of the sRGB gamut.
+/// Greys have no hue and are left unchanged.
+pub fn saturate(colour: impl Into<Rgb<u8>>, amount: f64) -> Rgb<u8> {
+    adjust(colour.into(), |lch| lch.c *= 1.0 + amount.max(0.0))
+}
+
+/// Desaturate a colour, reducing its OKLCH chroma by a fraction from 0 to 1
+///
+/// An amount of 1 gives a grey of the same lightness.
+pub fn desaturate(colour: impl Into<Rgb<u8>>, amount: f64) -> Rgb<u8> {
+    adjust(colour.into(), |lch| lch.c *= 1.0 - amount.clamp(0.0, 1.0))
+}
+
+fn adjust(colour: Rgb<u8>, change: impl FnOnce(&mut Oklch)) -> Rgb<u8> {
+    let mut lch = Oklch::from(colour);
+    change(&mut lch);
+    lch.into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::contrast::relative_luminance;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(Space::Srgb, Rgb::new(128, 128, 128))]
+    #[case(Space::Linear, Rgb::new(188, 188, 188))]
+    #[case(Space::Oklab, Rgb::new(99, 99, 99))]
+    fn test_mix_black_and_white(#[case] space: Space, #[case] expected: Rgb<u8>) {
+        assert_eq!(expected, mix(BLACK, WHITE, 0.5, space));
+    }
+
+    #[rstest]
+    #[case(Space::Srgb)]
+    #[case(Space::Linear)]
+    #[case(Space::Oklab)]
+    fn test_mix_ends(#[case] space: Space) {
+        let (first, second) = (Rgb::new(0, 128, 128), Rgb::new(255, 165, 0));
+
+        assert_eq!(first, mix(first, second, 0.0, space));
+        assert_eq!(second, mix(first, second, 1.0, space));
+        assert_eq!(second, mix(first, second, 2.0, space));
+    }
+
+    #[rstest]
+    #[case(Space::Srgb)]
+    #[case(Space::Linear)]
+    #[case(Space::Oklab)]
+    fn test_scales_step_monotonically(#[case] space: Space) {
+        let teal = Rgb::new(0, 128, 128);
+
+        let tints = tints(teal, 9, space);
+        let shades = shades(teal, 9, space);
+        assert_eq!(9, tints.len());
+        assert_eq!(teal, tints[0]);
+        assert_eq!(teal, shades[0]);
+        for pair in tints.windows(2) {
+            assert!(relative_luminance(pair[0]) < relative_luminance(pair[1]));
+        }
+        for pair in shades.windows(2) {
+            assert!(relative_luminance(pair[0]) > relative_luminance(pair[1]));
+        }
+    }
+
+    #[test]
+    fn test_tones_lose_chroma() {
+        let tones = tones(Rgb::new(255, 0, 0), 4, Space::Oklab);
+        let chroma: Vec<f64> = tones.iter().map(|&tone| Oklch::from(tone).c).collect();
+
+        assert!(chroma.windows(2).all(|pair| pair[0] > pair[1]));
+    }
+
+    #[test]
+    fn test_empty_scale() {
+        assert!(tints(WHITE, 0, Space::Oklab).is_empty());
+    }
+
+    #[test]
+    fn test_lighten_and_darken() {
+        let colour = Rgb::new(70, 130, 180);
+        let l = Oklch::from(colour).l;
+
+        assert_eq!(colour, lighten(colour, 0.0));
+        assert_eq!(WHITE, lighten(colour, 1.0));
+        assert_eq!(BLACK, darken(colour, 1.0));
+        assert!(Oklch::from(lighten(colour, 0.3)).l > l);
+        assert!(Oklch::from(darken(colour, 0.3)).l < l);
+    }
+
+    #[test]
+    fn test_saturate_and_desaturate() {
+        let colour = Rgb::new(70, 130, 180);
+        let lch = Oklch::from(colour);
+
+        let saturated = Oklch::from(saturate(colour, 0.5));
+        assert!(saturated.c > lch.c);
+        assert!((saturated.h - lch.h).abs() < 2.0);
+
+        let grey = desaturate(colour, 1.0);
+        assert!(grey.r.abs_diff(grey.g) <= 1 && grey.g.abs_diff(grey.b) <= 1);
+        assert_eq!(GREY, saturate(GREY, 1.0));
+    }
+
+    #[cfg(feature = "extended")]
+    #[test]
+    fn test_named_colours() {
+        use crate::ext::{Black, Cyan, White};
+
+        assert_eq!(Cyan::Teal.to_rgb(), tints(Cyan::Teal, 5, Space::Oklab)[0]);
+        assert_eq!(
+            Black::Gray.to_rgb(),
+            mix(Black::Black, White::White, 0.5, Space::Srgb)
+        );
+    }
+}
@@ -0,0 +1,463 @@
+//! Tables of named colours
+//!
+//! Every colour enum is generated by `define_colours!` from a single table of
+//! rows, so that the hex code shown by `Display`, the codes and names accepted
+//! by `parse()` and the value of `to_rgb()` cannot drift apart. Each table is
+//! checked with [`check`] when the crate is compiled.
+//!
+
+use crate::RGB8;
+
+/// A row of a colour table
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Row<T: 'static> {
+    /// The colour variant
+    pub colour: T,
+    /// The name of the variant
+    pub name: &'static str,
+    /// The hex code as `#RRGGBB` in uppercase
+    pub hex: &'static str,
+    /// The RGB value decoded from the hex code
+    pub rgb: RGB8,
+    /// The name of the variant this is an alias of
+    pub alias_of: Option<&'static str>,
+}
+
+/// The longest name a colour table can hold
+pub(crate) const NAME_LEN: usize = 32;
+
+/// Sorted keys for looking up the rows of a colour table
+///
+/// Built in `const` context by `define_colours!`, so that `parse()` finds a
+/// colour by binary search without allocating.
+pub struct Index<const N: usize> {
+    /// Name keys in order, with the name in lowercase and the row they belong to
+    names: [(u32, [u8; NAME_LEN], usize); N],
+    /// RGB keys in order, with the row they belong to
+    rgbs: [(u32, usize); N],
+}
+
+impl<const N: usize> Index<N> {
+    /// Build the index of a table with `N` rows
+    pub const fn new<T>(table: &[Row<T>]) -> Self {
+        if table.len() != N {
+            panic!("colour table index has a different length to the table");
+        }
+
+        let mut names = [(0, [0; NAME_LEN], 0); N];
+        let mut rgbs = [(0, 0); N];
+        let mut i = 0;
+        while i < N {
+            let row = &table[i];
+            let name = row.name.as_bytes();
+            let key = match name_key(name) {
+                Some(key) => key,
+                None => panic!("colour table name is empty or too long"),
+            };
+            let mut lowercase = [0; NAME_LEN];
+            let mut j = 0;
+            while j < name.len() {
+                lowercase[j] = name[j].to_ascii_lowercase();
+                j += 1;
+            }
+            names[i] = (key, lowercase, i);
+            rgbs[i] = (rgb_key(row.rgb) | row.alias_of.is_some() as u32, i);
+            i += 1;
+        }
+
+        let mut i = 1;
+        while i < N {
+            let mut j = i;
+            while j > 0 && names[j - 1].0 > names[j].0 {
+                (names[j - 1], names[j]) = (names[j], names[j - 1]);
+                j -= 1;
+            }
+            let mut j = i;
+            while j > 0 && rgbs[j - 1].0 > rgbs[j].0 {
+                (rgbs[j - 1], rgbs[j]) = (rgbs[j], rgbs[j - 1]);
+                j -= 1;
+            }
+            i += 1;
+        }
+
+        Self { names, rgbs }
+    }
+
+    /// Find the row with a hex code or name, ignoring ASCII case
+    ///
+    /// Hex codes are accepted with or without a `#` prefix and resolve to the
+    /// row that is not an alias. Names resolve to their own row.
+    pub fn find(&self, input: &str) -> Option<usize> {
+        if let Some(rgb) = decode_hex(input.as_bytes()) {
+            let key = rgb_key(rgb);
+            let position = self.rgbs.partition_point(|&(k, _)| k < key);
+            if let Some(&(k, row)) = self.rgbs.get(position) {
+                if k == key {
+                    return Some(row);
+                }
+            }
+        }
+
+        let name = input.as_bytes();
+        let key = name_key(name)?;
+        let position = self.names.partition_point(|&(k, _, _)| k < key);
+        let candidates = self.names[position..]
+            .iter()
+            .take_while(|&&(k, _, _)| k == key);
+
+        let mut lowercase = [0; NAME_LEN];
+        let lowercase = &mut lowercase[..name.len()];
+        lowercase.copy_from_slice(name);
+        lowercase.make_ascii_lowercase();
+
+        candidates
+            .into_iter()
+            .find(|(_, candidate, _)| &candidate[..name.len()] == lowercase)
+            .map(|&(_, _, row)| row)
+    }
+}
+
+/// The RGB key of the row that is not an alias, leaving the lowest bit to
+/// order aliases after it
+const fn rgb_key(rgb: RGB8) -> u32 {
+    ((rgb.r as u32) << 17) | ((rgb.g as u32) << 9) | ((rgb.b as u32) << 1)
+}
+
+/// The name key of a name: its length with its first and last bytes in
+/// lowercase, or `None` if it is empty or longer than [`NAME_LEN`]
+///
+/// Names with the same key are told apart by comparing them in full.
+const fn name_key(name: &[u8]) -> Option<u32> {
+    match name {
+        [first, .., last] | [first @ last] if name.len() <= NAME_LEN => Some(
+            ((name.len() as u32) << 16)
+                | ((first.to_ascii_lowercase() as u32) << 8)
+                | last.to_ascii_lowercase() as u32,
+        ),
+        _ => None,
+    }
+}
+
+/// Check a colour table, panicking on the first problem found
+///
+/// - every hex code is `#RRGGBB` with uppercase digits
+/// - names are unique ignoring ASCII case and at most `NAME_LEN` (32) bytes
+/// - hex codes are unique, except that an alias shares the code of the variant
+///   it is an alias of, which must exist and not itself be an alias
+///
+/// Evaluated in a `const` item by `colour_table!` so that a bad table fails to
+/// compile.
+pub const fn check<T>(table: &[Row<T>]) {
+    let mut i = 0;
+    while i < table.len() {
+        let row = &table[i];
+        if !is_hex_code(row.hex) {
+            panic!("colour table hex codes must be written as #RRGGBB in uppercase");
+        }
+        if row.name.is_empty() || row.name.len() > NAME_LEN {
+            panic!("colour table names must be between 1 and 32 bytes");
+        }
+
+        if let Some(canonical) = row.alias_of {
+            let mut found = false;
+            let mut j = 0;
+            while j < table.len() {
+                let other = &table[j];
+                if eq_ignore_ascii_case(other.name, canonical) {
+                    if other.alias_of.is_some() {
+                        panic!("colour table alias refers to another alias");
+                    }
+                    if !eq_ignore_ascii_case(other.hex, row.hex) {
+                        panic!("colour table alias has a different hex code to its colour");
+                    }
+                    found = true;
+                }
+                j += 1;
+            }
+            if !found {
+                panic!("colour table alias refers to a missing colour");
+            }
+        }
+
+        let mut j = i + 1;
+        while j < table.len() {
+            let other = &table[j];
+            if eq_ignore_ascii_case(row.name, other.name) {
+                panic!("colour table has a duplicate name");
+            }
+            if row.alias_of.is_none()
+                && other.alias_of.is_none()
+                && eq_ignore_ascii_case(row.hex, other.hex)
+            {
+                panic!("colour table has a duplicate hex code that is not marked as an alias");
+            }
+            j += 1;
+        }
+        i += 1;
+    }
+}
+
+/// Decode a `#RRGGBB` hex code in `const` context
+pub const fn hex_to_rgb(hex: &str) -> RGB8 {
+    let bytes = hex.as_bytes();
+    if bytes.is_empty() || bytes[0] != b'#' {
+        panic!("hex code must be written as #RRGGBB");
+    }
+    match decode_hex(bytes) {
+        Some(rgb) => rgb,
+        None => panic!("hex code must be written as #RRGGBB"),
+    }
+}
+
+/// Decode six hex digits with an optional `#` prefix
+const fn decode_hex(bytes: &[u8]) -> Option<RGB8> {
+    let digits = match bytes {
+        [b'#', digits @ ..] => digits,
+        digits => digits,
+    };
+    if digits.len() != 6 {
+        return None;
+    }
+
+    match (
+        hex_byte(digits[0], digits[1]),
+        hex_byte(digits[2], digits[3]),
+        hex_byte(digits[4], digits[5]),
+    ) {
+        (Some(r), Some(g), Some(b)) => Some(RGB8::new(r, g, b)),
+        _ => None,
+    }
+}
+
+pub(crate) const fn hex_byte(high: u8, low: u8) -> Option<u8> {
+    match (hex_digit(high), hex_digit(low)) {
+        (Some(high), Some(low)) => Some(high * 16 + low),
+        _ => None,
+    }
+}
+
+const fn hex_digit(byte: u8) -> Option<u8> {
+    match byte {
+        b'0'..=b'9' => Some(byte - b'0'),
+        b'a'..=b'f' => Some(byte - b'a' + 10),
+        b'A'..=b'F' => Some(byte - b'A' + 10),
+        _ => None,
+    }
+}
+
+const fn is_hex_code(hex: &str) -> bool {
+    let bytes = hex.as_bytes();
+    if bytes.len() != 7 || bytes[0] != b'#' {
+        return false;
+    }
+    let mut i = 1;
+    while i < bytes.len() {
+        if !matches!(bytes[i], b'0'..=b'9' | b'A'..=b'F') {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// Find the row with a name, ignoring ASCII case, in `const` context
+///
+/// A linear search for compile time lookups; `Index::find` is used at runtime.
+pub const fn position<T>(table: &[Row<T>], name: &str) -> Option<usize> {
+    let mut i = 0;
+    while i < table.len() {
+        if eq_ignore_ascii_case(table[i].name, name) {
+            return Some(i);
+        }
+        i += 1;
+    }
+    None
+}
+
+const fn eq_ignore_ascii_case(a: &str, b: &str) -> bool {
+    let (a, b) = (a.as_bytes(), b.as_bytes());
+    if a.len() != b.len() {
+        return false;
+    }
+    let mut i = 0;
+    while i < a.len() {
+        if !a[i].eq_ignore_ascii_case(&b[i]) {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// Assert that `Display`, `FromStr`, `name()` and `to_rgb()` agree for every row
+#[cfg(test)]
+pub(crate) fn assert_round_trip<T>(table: &[Row<T>])
+where
+    T: Copy
+        + PartialEq
+        + std::fmt::Debug
+        + std::fmt::Display
+        + std::str::FromStr
+        + Into<crate::RGB8>,
+    T::Err: std::fmt::Debug,
+{
+    use crate::ToHex;
+
+    for row in table {
+        let display = row.colour.to_string();
+        let rgb: crate::RGB8 = row.colour.into();
+        assert_eq!(row.hex, display);
+        assert_eq!(row.rgb, rgb);
+        assert_eq!(display, rgb.as_hex(), "{}", row.name);
+
+        let expected = match row.alias_of {
+            Some(canonical) => table.iter().find(|r| r.name == canonical).unwrap().colour,
+            None => row.colour,
+        };
+        assert_eq!(expected, display.parse::<T>().unwrap(), "{}", row.name);
+        assert_eq!(
+            expected,
+            display[1..].to_lowercase().parse::<T>().unwrap(),
+            "{}",
+            row.name
+        );
+        assert_eq!(rgb, display.parse::<T>().unwrap().into(), "{}", row.name);
+
+        assert_eq!(row.colour, row.name.parse::<T>().unwrap());
+        assert_eq!(row.colour, row.name.to_lowercase().parse::<T>().unwrap());
+        assert_eq!(row.colour, row.name.to_uppercase().parse::<T>().unwrap());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    const fn row(name: &'static str, hex: &'static str, alias_of: Option<&'static str>) -> Row<()> {
+        Row {
+            colour: (),
+            name,
+            hex,
+            rgb: RGB8::new(0, 0, 0),
+            alias_of,
+        }
+    }
+
+    const GREYS: [Row<()>; 4] = [
+        row("Silver", "#C0C0C0", None),
+        row("grey", "#808080", Some("Gray")),
+        row("Gray", "#808080", None),
+        row("Black", "#000000", None),
+    ];
+
+    fn row_with_rgb(
+        name: &'static str,
+        hex: &'static str,
+        alias_of: Option<&'static str>,
+    ) -> Row<&'static str> {
+        Row {
+            colour: name,
+            name,
+            hex,
+            rgb: hex_to_rgb(hex),
+            alias_of,
+        }
+    }
+
+    #[test]
+    fn test_index_order() {
+        let table: Vec<_> = GREYS
+            .iter()
+            .map(|r| row_with_rgb(r.name, r.hex, r.alias_of))
+            .collect();
+        let index = Index::<4>::new(&table);
+
+        let names: Vec<_> = index.names.iter().map(|(_, _, row)| *row).collect();
+        let rgbs: Vec<_> = index.rgbs.iter().map(|(_, row)| *row).collect();
+        assert_eq!(vec![1, 2, 3, 0], names);
+        assert_eq!(vec![3, 2, 1, 0], rgbs);
+    }
+
+    #[rstest]
+    #[case("#808080", Some("Gray"))]
+    #[case("808080", Some("Gray"))]
+    #[case("#c0c0c0", Some("Silver"))]
+    #[case("GREY", Some("grey"))]
+    #[case("gray", Some("Gray"))]
+    #[case("black", Some("Black"))]
+    #[case("#000001", None)]
+    #[case("#black", None)]
+    #[case("blac", None)]
+    #[case("bLACK", Some("Black"))]
+    #[case("brack", None)]
+    #[case("blackblackblackblackblackblackblack", None)]
+    #[case("", None)]
+    fn test_find(#[case] input: &str, #[case] expected: Option<&str>) {
+        let table: Vec<_> = GREYS
+            .iter()
+            .map(|r| row_with_rgb(r.name, r.hex, r.alias_of))
+            .collect();
+        let index = Index::<4>::new(&table);
+
+        assert_eq!(expected, index.find(input).map(|row| table[row].colour));
+    }
+
+    #[rstest]
+    #[case("Silver", Some(0))]
+    #[case("GREY", Some(1))]
+    #[case("gray", Some(2))]
+    #[case("#000000", None)]
+    #[case("", None)]
+    fn test_position(#[case] name: &str, #[case] expected: Option<usize>) {
+        assert_eq!(expected, position(&GREYS, name));
+    }
+
+    #[test]
+    fn test_check_valid_table() {
+        check(&[
+            row("Gray", "#808080", None),
+            row("Grey", "#808080", Some("Gray")),
+            row("Silver", "#C0C0C0", None),
+        ]);
+    }
+
+    #[rstest]
+    #[case("#000000", RGB8::new(0, 0, 0))]
+    #[case("#4682B4", RGB8::new(70, 130, 180))]
+    #[case("#dc143c", RGB8::new(220, 20, 60))]
+    #[case("#FFFFFF", RGB8::new(255, 255, 255))]
+    fn test_hex_to_rgb(#[case] hex: &str, #[case] expected: RGB8) {
+        assert_eq!(expected, hex_to_rgb(hex));
+    }
+
+    #[rstest]
+    #[case("808080")]
+    #[case("#8080800")]
+    #[case("#80808")]
+    #[case("#80808G")]
+    #[should_panic]
+    fn test_hex_to_rgb_invalid(#[case] hex: &str) {
+        hex_to_rgb(hex);
+    }
+
+    #[rstest]
+    #[case(&[row("Gray", "#80808", None)])]
+    #[case(&[row("Gray", "#80808g", None)])]
+    #[case(&[row("Gray", "#80808a", None)])]
+    #[case(&[row("Gray", "808080", None)])]
+    #[case(&[row("Gray", "#808080", None), row("gray", "#C0C0C0", None)])]
+    #[case(&[row("GrayGrayGrayGrayGrayGrayGrayGrayGray", "#808080", None)])]
+    #[case(&[row("Gray", "#808080", None), row("Grey", "#808080", None)])]
+    #[case(&[row("Gray", "#808080", None), row("Grey", "#C0C0C0", Some("Gray"))])]
+    #[case(&[row("Grey", "#808080", Some("Gray"))])]
+    #[case(&[
+        row("Gray", "#808080", None),
+        row("Grey", "#808080", Some("Gray")),
+        row("Gris", "#808080", Some("Grey")),
+    ])]
+    #[should_panic]
+    fn test_check_invalid_table(#[case] table: &[Row<()>]) {
+        check(table);
+    }
+}
@@ -0,0 +1,183 @@
+//! Colour temperature
+//!
+//! [`from_kelvin`] gives the colour of a black body at a temperature from
+//! 1000 K to 40000 K, by integrating Planck's law against the CIE 1931
+//! colour matching functions and scaling the result to the brightest sRGB
+//! colour of that chromaticity. [`cct`] estimates the correlated colour
+//! temperature of a colour with McCamy's approximation, which is most
+//! accurate for near white colours from about 2000 K to 12500 K.
+//!
+//! ## Example
+//!
+//! ```
+//! use named_colour::temperature::{cct, from_kelvin};
+//! use rgb::Rgb;
+//!
+//! let candle = from_kelvin(1900.0);
+//! assert_eq!(Rgb::new(255, 135, 0), candle);
+//!
+//! let daylight = cct(Rgb::new(255, 255, 255)).unwrap();
+//! assert_eq!(6503, daylight.round() as u32);
+//! ```
+//!
+
+use rgb::Rgb;
+
+use crate::lab::{encode, linear};
+
+/// The lowest temperature in kelvin given a colour by [`from_kelvin`]
+pub const MIN_KELVIN: f64 = 1_000.0;
+/// The highest temperature in kelvin given a colour by [`from_kelvin`]
+pub const MAX_KELVIN: f64 = 40_000.0;
+
+/// The colour of a black body at a temperature in kelvin
+///
+/// The temperature is clamped between [`MIN_KELVIN`] and [`MAX_KELVIN`].
+/// Colours outside the sRGB gamut, as below about 1900 K, are clipped.
+pub fn from_kelvin(kelvin: f64) -> Rgb<u8> {
+    let [x, y, z] = black_body(kelvin.clamp(MIN_KELVIN, MAX_KELVIN));
+
+    let rgb = [
+        3.240_454_2 * x - 1.537_138_5 * y - 0.498_531_4 * z,
+        -0.969_266 * x + 1.876_010_8 * y + 0.041_556 * z,
+        0.055_643_4 * x - 0.204_025_9 * y + 1.057_225_2 * z,
+    ]
+    .map(|c| c.max(0.0));
+    let max = rgb[0].max(rgb[1]).max(rgb[2]);
+
+    Rgb::new(
+        encode(rgb[0] / max),
+        encode(rgb[1] / max),
+        encode(rgb[2] / max),
+    )
+}
+
+/// The correlated colour temperature of a colour in kelvin
+///
+/// Returns `None` for black, which has no chromaticity.
+pub fn cct(colour: impl Into<Rgb<u8>>) -> Option<f64> {
+    let rgb = colour.into();
+    let (r, g, b) = (linear(rgb.r), linear(rgb.g), linear(rgb.b));
+
+    let x = 0.412_456_4 * r + 0.357_576_1 * g + 0.180_437_5 * b;
+    let y = 0.212_672_9 * r + 0.715_152_2 * g + 0.072_175 * b;
+    let z = 0.019_333_9 * r + 0.119_192 * g + 0.950_304_1 * b;
+    let sum = x + y + z;
+    if sum == 0.0 {
+        return None;
+    }
+
+    let n = (x / sum - 0.332) / (0.1858 - y / sum);
+    Some(449.0 * n.powi(3) + 3525.0 * n.powi(2) + 6823.3 * n + 5520.33)
+}
+
+/// The named colour nearest to the colour of a black body at a temperature
+/// in kelvin
+///
+/// ## Example
+///
+/// ```
+/// # use named_colour::temperature::nearest_named_colour;
+///     let colour = nearest_named_colour(2700.0);
+///
+///     assert_eq!("SandyBrown", colour.name());
+/// ```
+#[cfg(feature = "extended")]
+pub fn nearest_named_colour(kelvin: f64) -> crate::ext::NamedColour {
+    crate::ext::NamedColour::nearest(from_kelvin(kelvin))
+}
+
+/// The CIE XYZ tristimulus of a black body, in arbitrary units
+fn black_body(kelvin: f64) -> [f64; 3] {
+    // The second radiation constant in metre kelvin
+    const C2: f64 = 1.438_776_9e-2;
+
+    (380..=780).fold([0.0; 3], |[x, y, z], nm| {
+        let lambda = nm as f64;
+        let metres = lambda * 1e-9;
+        let radiance = 1.0 / (metres.powi(5) * ((C2 / (metres * kelvin)).exp() - 1.0));
+
+        let [xb, yb, zb] = matching_functions(lambda);
+        [x + radiance * xb, y + radiance * yb, z + radiance * zb]
+    })
+}
+
+/// The CIE 1931 colour matching functions at a wavelength in nanometres, from
+/// the multi-lobe fit of Wyman, Sloan and Shirley (2013)
+fn matching_functions(lambda: f64) -> [f64; 3] {
+    let lobe = |mu: f64, below: f64, above: f64| {
+        let sigma = if lambda < mu { below } else { above };
+        (-0.5 * ((lambda - mu) / sigma).powi(2)).exp()
+    };
+
+    [
+        1.056 * lobe(599.8, 37.9, 31.0) + 0.362 * lobe(442.0, 16.0, 26.7)
+            - 0.065 * lobe(501.1, 20.4, 26.2),
+        0.821 * lobe(568.8, 46.9, 40.5) + 0.286 * lobe(530.9, 16.3, 31.1),
+        1.217 * lobe(437.0, 11.8, 36.0) + 0.681 * lobe(459.0, 26.0, 13.8),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(1000.0, Rgb::new(255, 47, 0))]
+    #[case(2700.0, Rgb::new(255, 174, 89))]
+    #[case(6500.0, Rgb::new(255, 249, 254))]
+    #[case(40000.0, Rgb::new(159, 184, 255))]
+    fn test_from_kelvin(#[case] kelvin: f64, #[case] expected: Rgb<u8>) {
+        assert_eq!(expected, from_kelvin(kelvin));
+    }
+
+    #[test]
+    fn test_from_kelvin_is_clamped() {
+        assert_eq!(from_kelvin(MIN_KELVIN), from_kelvin(0.0));
+        assert_eq!(from_kelvin(MAX_KELVIN), from_kelvin(1e9));
+    }
+
+    #[test]
+    fn test_from_kelvin_gets_bluer() {
+        let colours: Vec<Rgb<u8>> = (1..=40).map(|k| from_kelvin(k as f64 * 1000.0)).collect();
+
+        for pair in colours.windows(2) {
+            let ratio = |rgb: Rgb<u8>| rgb.b as f64 / rgb.r as f64;
+            assert!(ratio(pair[0]) <= ratio(pair[1]), "{pair:?}");
+        }
+    }
+
+    #[rstest]
+    #[case(2500.0)]
+    #[case(3000.0)]
+    #[case(4000.0)]
+    #[case(5000.0)]
+    #[case(6500.0)]
+    #[case(10000.0)]
+    fn test_cct_round_trip(#[case] kelvin: f64) {
+        let estimate = cct(from_kelvin(kelvin)).unwrap();
+
+        assert!((estimate - kelvin).abs() / kelvin < 0.03, "{estimate}");
+    }
+
+    #[test]
+    fn test_cct_of_black() {
+        assert_eq!(None, cct(Rgb::new(0, 0, 0)));
+    }
+
+    #[cfg(feature = "extended")]
+    #[test]
+    fn test_named_colours() {
+        use crate::ext::White;
+
+        let ivory = cct(White::Ivory).unwrap();
+        let alice_blue = cct(White::AliceBlue).unwrap();
+        assert!(ivory < alice_blue);
+
+        assert_eq!(
+            crate::ext::NamedColour::nearest(from_kelvin(6500.0)),
+            nearest_named_colour(6500.0)
+        );
+    }
+}
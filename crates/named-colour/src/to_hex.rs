@@ -1,5 +1,7 @@
 use rgb::Rgb;
 
+use crate::table::hex_byte;
+
 /// Implement the `ToHex` trait
 ///
 /// Provides interfaces for functions to display hex versions of RGB colours
@@ -23,10 +25,35 @@ impl ToHex for Rgb<u8> {
     }
 }
 
+/// Parse a hex colour code with an optional `#` prefix
+///
+/// Accepts six digit codes such as `#4682B4` and the three digit shorthand
+/// such as `#ABC`, in either case. This is a `const fn` so that `hex!` can
+/// check its literal when the crate using it is compiled.
+#[doc(hidden)]
+pub const fn parse_hex(hex: &str) -> Option<Rgb<u8>> {
+    let digits = match hex.as_bytes() {
+        [b'#', digits @ ..] => digits,
+        digits => digits,
+    };
+
+    let (r, g, b) = match *digits {
+        [r1, r2, g1, g2, b1, b2] => (hex_byte(r1, r2), hex_byte(g1, g2), hex_byte(b1, b2)),
+        [r, g, b] => (hex_byte(r, r), hex_byte(g, g), hex_byte(b, b)),
+        _ => return None,
+    };
+
+    match (r, g, b) {
+        (Some(r), Some(g), Some(b)) => Some(Rgb::new(r, g, b)),
+        _ => None,
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use super::ToHex;
+    use super::{parse_hex, ToHex};
     use rgb::Rgb;
+    use rstest::rstest;
 
     #[test]
     fn print_valid_hex_string_for_rgb_u8() {
@@ -44,4 +71,17 @@ mod tests {
         let colour = Rgb::new(12, 4, 8);
         assert_eq!("0c0408", colour.to_hex_string());
     }
+
+    #[rstest]
+    #[case("#4682B4", Some(Rgb::new(70, 130, 180)))]
+    #[case("4682b4", Some(Rgb::new(70, 130, 180)))]
+    #[case("#abc", Some(Rgb::new(170, 187, 204)))]
+    #[case("FFF", Some(Rgb::new(255, 255, 255)))]
+    #[case("#4682B", None)]
+    #[case("#+682B4", None)]
+    #[case("#4682G4", None)]
+    #[case("", None)]
+    fn test_parse_hex(#[case] hex: &str, #[case] expected: Option<Rgb<u8>>) {
+        assert_eq!(expected, parse_hex(hex));
+    }
 }